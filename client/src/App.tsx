@@ -5,15 +5,22 @@ import 'aos/dist/aos.css'
 import { BrowserRouter as Router, Routes, Route } from 'react-router-dom'
 import LandingPage from './components/LandingPage/LandingPage'
 import { WalletApi } from '@concordium/browser-wallet-api-helpers'
+import { ContractAddress } from '@concordium/web-sdk'
 
 interface context {
   user: string | undefined
   client: WalletApi | undefined
+  dao: ContractAddress.Type | undefined
+  setDAO: React.Dispatch<
+    React.SetStateAction<ContractAddress.Type | undefined>
+  >
 }
 
 export const UserContext = createContext<context>({
   user: undefined,
   client: undefined,
+  dao: undefined,
+  setDAO: () => {},
 })
 
 const App = () => {
@@ -29,9 +36,12 @@ const App = () => {
 
   const [user, setUser] = useState<string | undefined>()
   const [client, setClient] = useState<WalletApi>()
+  const [dao, setDAO] = useState<ContractAddress.Type>()
 
   return (
-    <UserContext.Provider value={{ user: user, client: client }}>
+    <UserContext.Provider
+      value={{ user: user, client: client, dao: dao, setDAO: setDAO }}
+    >
       <div className="bg-white dark:bg-gray-900 dark:text-white duration-200 overflow-x-hidden">
         <Router>
           <Routes>
@@ -80,6 +80,7 @@ const FormPage: React.FC<Props> = ({ setTxHash }) => {
       console.log('Form 1 submitted', form1Values)
       let res = await createProposal(
         ctx.client!,
+        ctx.dao!,
         form1Values.description,
         form1Values.amount,
         ctx.user!
@@ -97,7 +98,12 @@ const FormPage: React.FC<Props> = ({ setTxHash }) => {
       // Handle form submission (e.g., send data to server)
       console.log('Form 2 submitted', form2Values)
 
-      let res = await insertFunds(ctx.client!, form2Values.amount, ctx.user!)
+      let res = await insertFunds(
+        ctx.client!,
+        ctx.dao!,
+        form2Values.amount,
+        ctx.user!
+      )
       await getTransactionReceipt(ctx.client!, res!)
       setTxHash(res) // Set the transaction hash state here
       console.log(res)
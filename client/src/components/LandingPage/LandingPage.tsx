@@ -5,28 +5,64 @@ import Proposals from '../Proposals/Proposals'
 import AllProposals from '../AllProposals/AllProposals'
 import { useContext, useEffect, useState } from 'react'
 import { UserContext } from '../../App'
-import { MemberArray, ProposalArray } from '../../types'
-import { getAllMembers, getAllProposals } from '../../utils/wallet'
+import { DAOArray, MemberArray, ProposalArray } from '../../types'
+import {
+  getAllMembers,
+  getAllProposals,
+  getRegisteredDAOs,
+} from '../../utils/wallet'
+import { ContractAddress } from '@concordium/web-sdk'
 
 const LandingPage = () => {
   const ctx = useContext(UserContext)
+  const [daos, setDAOs] = useState<DAOArray>([])
   const [proposals, setProposals] = useState<ProposalArray>([])
   const [power, setPower] = useState<bigint>()
   const [txHash, setTxHash] = useState<string | undefined>(undefined)
 
   useEffect(() => {
-    getAllProposals(ctx.client!).then(setProposals).catch(console.error)
-    getAllMembers(ctx.client!)
+    getRegisteredDAOs(ctx.client!)
+      .then((daos: DAOArray) => {
+        setDAOs(daos)
+        if (ctx.dao === undefined && daos.length > 0) {
+          const [address] = daos[0]
+          ctx.setDAO(ContractAddress.create(address.index, address.subindex))
+        }
+      })
+      .catch(console.error)
+  }, [])
+
+  useEffect(() => {
+    if (ctx.dao === undefined) {
+      return
+    }
+    getAllProposals(ctx.client!, ctx.dao)
+      .then(setProposals)
+      .catch(console.error)
+    getAllMembers(ctx.client!, ctx.dao)
       .then((members: MemberArray) => {
         let memberMap = new Map(members)
         setPower(memberMap.get(ctx.user!))
       })
       .catch(console.error)
-  }, [txHash])
+  }, [txHash, ctx.dao])
 
   return (
     <>
       <Navbar />
+      <select
+        className="m-4 p-2 rounded-md border border-gray-300 dark:bg-gray-800"
+        value={ctx.dao === undefined ? '' : String(ctx.dao.index)}
+        onChange={(e) =>
+          ctx.setDAO(ContractAddress.create(BigInt(e.target.value), 0))
+        }
+      >
+        {daos.map(([address, entry]) => (
+          <option key={String(address.index)} value={String(address.index)}>
+            {entry.name}
+          </option>
+        ))}
+      </select>
       <Home />
       <FormPage setTxHash={setTxHash} />
       <Proposals
@@ -24,7 +24,7 @@ const Modal: React.FC<Props> = ({
   power,
   setTxHash,
 }) => {
-  const { user, client } = useContext(UserContext)
+  const { user, client, dao } = useContext(UserContext)
   const [voteNumber, setVoteNumber] = useState(0)
   const [voteError, setVoteError] = useState('')
 
@@ -64,7 +64,7 @@ const Modal: React.FC<Props> = ({
     } else {
       setVoteError('')
       console.log(voteNumber)
-      let res = await voteForProposal(client!, data[0], voteNumber, user!)
+      let res = await voteForProposal(client!, dao!, data[0], voteNumber, user!)
       await getTransactionReceipt(client!, res!)
       setTxHash(res)
       console.log(res)
@@ -75,7 +75,7 @@ const Modal: React.FC<Props> = ({
 
   const handleRenounce = async (renounce: bigint) => {
     console.log(renounce)
-    let res = await renounceVotes(client!, data[0], renounce, user!)
+    let res = await renounceVotes(client!, dao!, data[0], renounce, user!)
     await getTransactionReceipt(client!, res!)
     setTxHash(res)
     console.log(res)
@@ -91,7 +91,7 @@ const Modal: React.FC<Props> = ({
   }
 
   const withdraw = async () => {
-    let res = await withdrawFunds(client!, data[0], user!)
+    let res = await withdrawFunds(client!, dao!, data[0], user!)
     await getTransactionReceipt(client!, res!)
     setTxHash(res)
     console.log(res)
@@ -1,7 +1,9 @@
 export const MODULE_REF: string =
   '26404867a1dcc88e913140ebc1950df8a3386d9a042cffaaf367e29d23f12c10'
 export const CONTRACT_NAME = 'DAO'
-export const CONTRACT_INDEX = 10042
+// DAOs are discovered through the registry instead of being pinned to a single instance.
+export const REGISTRY_CONTRACT_NAME = 'DAORegistry'
+export const REGISTRY_INDEX = Number(import.meta.env.VITE_REGISTRY_INDEX)
 export const RAW_SCHEMA_BASE64 =
   '//8DAQAAAAMAAABEQU8ABwAAAAsAAABhbGxfbWVtYmVycwUQAg8LBRUHAAAACwAAAFBhcnNlUGFyYW1zAgwAAABVbmF1dGhvcml6ZWQCEAAAAFByb3Bvc2FsTm90Rm91bmQCCwAAAE5vdEFwcHJvdmVkAg8AAABBbHJlYWR5QXBwcm92ZWQCEwAAAEluc3VmZmljaWVudEJhbGFuY2UCDwAAAEFtb3VudENvbGxlY3RlZAINAAAAYWxsX3Byb3Bvc2FscwUQAg8FFAAGAAAACAAAAHByb3Bvc2VyCwsAAABkZXNjcmlwdGlvbhYCBgAAAGFtb3VudAoFAAAAdm90ZXMFDAAAAGNvbnRyaWJ1dGVycxACDwsFBgAAAHN0YXR1cxUDAAAABgAAAEFjdGl2ZQIIAAAAQXBwcm92ZWQCCQAAAENvbGxlY3RlZAIVBwAAAAsAAABQYXJzZVBhcmFtcwIMAAAAVW5hdXRob3JpemVkAhAAAABQcm9wb3NhbE5vdEZvdW5kAgsAAABOb3RBcHByb3ZlZAIPAAAAQWxyZWFkeUFwcHJvdmVkAhMAAABJbnN1ZmZpY2llbnRCYWxhbmNlAg8AAABBbW91bnRDb2xsZWN0ZWQCDwAAAGNyZWF0ZV9wcm9wb3NhbAQUAAIAAAALAAAAZGVzY3JpcHRpb24WAgYAAABhbW91bnQKFQcAAAALAAAAUGFyc2VQYXJhbXMCDAAAAFVuYXV0aG9yaXplZAIQAAAAUHJvcG9zYWxOb3RGb3VuZAILAAAATm90QXBwcm92ZWQCDwAAAEFscmVhZHlBcHByb3ZlZAITAAAASW5zdWZmaWNpZW50QmFsYW5jZQIPAAAAQW1vdW50Q29sbGVjdGVkAgkAAABnZXRfcG93ZXIGFAABAAAABwAAAGFkZHJlc3MLBRUHAAAACwAAAFBhcnNlUGFyYW1zAgwAAABVbmF1dGhvcml6ZWQCEAAAAFByb3Bvc2FsTm90Rm91bmQCCwAAAE5vdEFwcHJvdmVkAg8AAABBbHJlYWR5QXBwcm92ZWQCEwAAAEluc3VmZmljaWVudEJhbGFuY2UCDwAAAEFtb3VudENvbGxlY3RlZAIIAAAAcmVub3VuY2UEFAACAAAACwAAAHByb3Bvc2FsX2lkBQUAAAB2b3RlcwUVBwAAAAsAAABQYXJzZVBhcmFtcwIMAAAAVW5hdXRob3JpemVkAhAAAABQcm9wb3NhbE5vdEZvdW5kAgsAAABOb3RBcHByb3ZlZAIPAAAAQWxyZWFkeUFwcHJvdmVkAhMAAABJbnN1ZmZpY2llbnRCYWxhbmNlAg8AAABBbW91bnRDb2xsZWN0ZWQCBAAAAHZvdGUEFAACAAAACwAAAHByb3Bvc2FsX2lkBQUAAAB2b3RlcwUVBwAAAAsAAABQYXJzZVBhcmFtcwIMAAAAVW5hdXRob3JpemVkAhAAAABQcm9wb3NhbE5vdEZvdW5kAgsAAABOb3RBcHByb3ZlZAIPAAAAQWxyZWFkeUFwcHJvdmVkAhMAAABJbnN1ZmZpY2llbnRCYWxhbmNlAg8AAABBbW91bnRDb2xsZWN0ZWQCCAAAAHdpdGhkcmF3ABQAAQAAAAsAAABwcm9wb3NhbF9pZAUA'
 
@@ -1,5 +1,13 @@
 export interface ProposalArray extends Array<[bigint, ProposalData]> {}
 export interface MemberArray extends Array<[string, bigint]> {}
+export interface DAOArray
+  extends Array<[{ index: bigint; subindex: bigint }, DAOEntry]> {}
+
+export interface DAOEntry {
+  name: string
+  creator: string
+  registered_at: string
+}
 
 export interface ProposalData {
   amount: string
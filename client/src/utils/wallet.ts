@@ -20,9 +20,10 @@ import {
   TransactionKindString,
 } from '@concordium/web-sdk'
 import {
-  CONTRACT_INDEX,
   CONTRACT_NAME,
   RAW_SCHEMA_BASE64,
+  REGISTRY_CONTRACT_NAME,
+  REGISTRY_INDEX,
   TESTNET_GENESIS_BLOCK_HASH,
 } from '../config/config'
 import Swal from 'sweetalert2'
@@ -49,6 +50,7 @@ async function checkConnectedToTestnet(client: WalletApi) {
 
 export async function createProposal(
   client: WalletApi,
+  dao: ContractAddress.Type,
   description: string,
   amount: number,
   senderAddress: string
@@ -61,7 +63,7 @@ export async function createProposal(
       {
         maxContractExecutionEnergy: BigInt(30000),
         amount: CcdAmount.fromMicroCcd(BigInt(0)),
-        address: { index: BigInt(CONTRACT_INDEX), subindex: BigInt(0) },
+        address: dao,
         receiveName: 'DAO.create_proposal',
       } as unknown as UpdateContractPayload,
       {
@@ -76,6 +78,7 @@ export async function createProposal(
 }
 export async function renounceVotes(
   client: WalletApi,
+  dao: ContractAddress.Type,
   proposalID: bigint,
   votes: bigint,
   senderAddress: string
@@ -87,7 +90,7 @@ export async function renounceVotes(
       AccountTransactionType.Update,
       {
         amount: CcdAmount.fromMicroCcd(BigInt(0)),
-        address: { index: BigInt(CONTRACT_INDEX), subindex: BigInt(0) },
+        address: dao,
         receiveName: 'DAO.renounce',
         maxContractExecutionEnergy: BigInt(30000),
       } as unknown as UpdateContractPayload,
@@ -104,6 +107,7 @@ export async function renounceVotes(
 
 export async function insertFunds(
   client: WalletApi,
+  dao: ContractAddress.Type,
   amount: number,
   senderAddress: string
 ) {
@@ -114,7 +118,7 @@ export async function insertFunds(
       AccountTransactionType.Update,
       {
         amount: CcdAmount.fromMicroCcd(BigInt(amount)), //Payable
-        address: { index: BigInt(CONTRACT_INDEX), subindex: BigInt(0) },
+        address: dao,
         receiveName: 'DAO.insert',
         maxContractExecutionEnergy: BigInt(30000),
       } as unknown as UpdateContractPayload //Only sending funds no parameters involved.
@@ -126,6 +130,7 @@ export async function insertFunds(
 
 export async function voteForProposal(
   client: WalletApi,
+  dao: ContractAddress.Type,
   proposalID: bigint,
   vote_decision: number,
   senderAddress: string
@@ -137,7 +142,7 @@ export async function voteForProposal(
       AccountTransactionType.Update,
       {
         amount: CcdAmount.fromMicroCcd(BigInt(0)),
-        address: { index: BigInt(CONTRACT_INDEX), subindex: BigInt(0) },
+        address: dao,
         receiveName: 'DAO.vote',
         maxContractExecutionEnergy: BigInt(30000),
       } as unknown as UpdateContractPayload,
@@ -154,6 +159,7 @@ export async function voteForProposal(
 
 export async function withdrawFunds(
   client: WalletApi,
+  dao: ContractAddress.Type,
   proposalID: bigint,
   senderAddress: string
 ) {
@@ -164,7 +170,7 @@ export async function withdrawFunds(
       AccountTransactionType.Update,
       {
         amount: CcdAmount.fromMicroCcd(BigInt(0)),
-        address: { index: BigInt(CONTRACT_INDEX), subindex: BigInt(0) },
+        address: dao,
         receiveName: 'DAO.withdraw',
         maxContractExecutionEnergy: BigInt(30000),
       } as unknown as UpdateContractPayload,
@@ -178,10 +184,32 @@ export async function withdrawFunds(
   }
 }
 
-export async function getAllMembers(client: WalletApi) {
+// Fetch the DAOs registered with the registry, so that the user can pick one of them.
+export async function getRegisteredDAOs(client: WalletApi) {
   const grpcClient = new ConcordiumGRPCClient(client.grpcTransport)
   const res = await grpcClient.invokeContract({
-    contract: ContractAddress.create(CONTRACT_INDEX, 0),
+    contract: ContractAddress.create(REGISTRY_INDEX, 0),
+    method: ReceiveName.fromString('DAORegistry.list'),
+  })
+
+  let daos = deserializeReceiveReturnValue(
+    toBuffer(res.returnValue!.toString(), 'hex'),
+    toBuffer(RAW_SCHEMA_BASE64, 'base64'),
+    ContractName.fromString(REGISTRY_CONTRACT_NAME),
+    EntrypointName.fromString('list'),
+    SchemaVersion.V2
+  )
+
+  return daos
+}
+
+export async function getAllMembers(
+  client: WalletApi,
+  dao: ContractAddress.Type
+) {
+  const grpcClient = new ConcordiumGRPCClient(client.grpcTransport)
+  const res = await grpcClient.invokeContract({
+    contract: dao,
     method: ReceiveName.fromString('DAO.all_members'),
   })
 
@@ -196,10 +224,13 @@ export async function getAllMembers(client: WalletApi) {
   return members
 }
 
-export async function getAllProposals(client: WalletApi) {
+export async function getAllProposals(
+  client: WalletApi,
+  dao: ContractAddress.Type
+) {
   const grpcClient = new ConcordiumGRPCClient(client.grpcTransport)
   const res = await grpcClient.invokeContract({
-    contract: ContractAddress.create(CONTRACT_INDEX, 0),
+    contract: dao,
     method: ReceiveName.fromString('DAO.all_proposals'),
   })
 
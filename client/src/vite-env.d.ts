@@ -1 +1,9 @@
 /// <reference types="vite/client" />
+
+interface ImportMetaEnv {
+  readonly VITE_REGISTRY_INDEX: string
+}
+
+interface ImportMeta {
+  readonly env: ImportMetaEnv
+}
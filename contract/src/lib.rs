@@ -1,21 +1,373 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use concordium_cis2::*;
 use concordium_std::*;
 use core::fmt::Debug;
+use registry::RegisterInput;
+
+pub mod registry;
+
+/// Basis points making up the whole.
+const MAX_BPS: u16 = 10_000;
+
+/// The standards implemented by the DAO contract.
+const SUPPORTS_STANDARDS: [StandardIdentifier<'static>; 2] =
+    [CIS0_STANDARD_IDENTIFIER, CIS2_STANDARD_IDENTIFIER];
+
+/// Token ID of the donation receipts, which is the index of the receipt.
+pub type ContractTokenId = TokenIdU32;
+
+/// Token amount of the donation receipts; every receipt is unique.
+pub type ContractTokenAmount = TokenAmountU8;
+
+/// Errors of the CIS-2 entrypoints.
+pub type ContractError = Cis2Error<DAOError>;
+
+/// CIS-2 events of the donation receipts.
+pub type ContractEvent = Cis2Event<ContractTokenId, ContractTokenAmount>;
 
 /// The state of the contract.
 #[derive(Serialize, SchemaType)]
 pub struct DAOState {
+    pub name: String,
     pub proposals: Vec<(u64, Proposal)>,
-    pub members: Vec<(AccountAddress, u64)>,
+    pub members: Vec<(Address, Member)>,
     pub origin: AccountAddress,
+    pub config: DAOConfig,
+    /// Number of times the config has been updated.
+    pub config_revision: u32,
+    /// Total amount ever deposited through [`DAO.insert`].
+    pub total_deposited: Amount,
+    /// Total amount paid out to collected proposals and funding round payouts.
+    pub disbursed: Amount,
+    /// Contracts implementing standards on behalf of the DAO.
+    pub implementors: Vec<(StandardIdentifierOwned, Vec<ContractAddress>)>,
+    pub disputes: Vec<(u64, Dispute)>,
+    /// Accounts barred from creating proposals after a successful dispute.
+    pub blocked: Vec<Address>,
+    pub rounds: Vec<(u64, Round)>,
+    /// Treasurers confirming payouts above the payout limit.
+    pub signers: Signers,
+    /// Total amount released from the reserve through emergency proposals.
+    pub reserve_released: Amount,
+    /// Donation receipts minted on deposits.
+    pub receipts: Vec<(ContractTokenId, Receipt)>,
+    /// Operators of receipt owners, as (owner, operator).
+    pub operators: Vec<(Address, Address)>,
+    /// Operations fees deducted from deposits and not collected yet.
+    pub fees: Amount,
+    /// Distribution of the treasury, once the DAO is dissolved.
+    pub dissolution: Option<Dissolution>,
+    /// Refunds to contracts that failed to receive them, claimable through [`DAO.claim_refund`].
+    pub owed: Vec<(ContractAddress, Amount)>,
+    /// Registry the DAO is registered with, refreshed on config updates.
+    pub registry: Option<ContractAddress>,
+    /// Accounts allowed to veto proposals and to set the signers and implementors.
+    pub guardians: Vec<AccountAddress>,
+}
+
+impl DAOState {
+    /// Returns the votes of the contributers to their voting power.
+    pub fn return_votes(&mut self, contributers: &[(Address, u64)]) -> Result<(), DAOError> {
+        for (voter, votes) in contributers.iter() {
+            for (account, member) in self.members.iter_mut() {
+                if account == voter {
+                    member.power = member.power.checked_add(*votes).ok_or(DAOError::Overflow)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Voting power that members have not spent yet.
+    pub fn outstanding_power(&self) -> Result<u64, DAOError> {
+        checked_sum(self.members.iter().map(|(_, m)| m.power))
+    }
+
+    /// Amount committed to approved proposals that are yet to be collected.
+    pub fn committed(&self) -> Result<Amount, DAOError> {
+        checked_sum_amounts(
+            self.proposals
+                .iter()
+                .filter(|(_, p)| p.status == Status::Approved)
+                .map(|(_, p)| p.amount),
+        )
+    }
+
+    /// Bonds and earmarked donations held for active proposals.
+    pub fn held(&self) -> Result<Amount, DAOError> {
+        let mut held = Amount::zero();
+        for (_, p) in self.proposals.iter() {
+            if p.status == Status::Active {
+                held = checked_add_amounts(held, p.bond)?;
+                held = checked_add_amounts(held, p.earmarked()?)?;
+            }
+        }
+        Ok(held)
+    }
+
+    /// Matching pools and contributions of funding rounds, including unclaimed payouts.
+    pub fn in_rounds(&self) -> Result<Amount, DAOError> {
+        let mut in_rounds = Amount::zero();
+        for (_, r) in self.rounds.iter() {
+            in_rounds = checked_add_amounts(in_rounds, r.reserved()?)?;
+        }
+        Ok(in_rounds)
+    }
+
+    /// Emergency fund which can only be released through emergency proposals: the larger of the
+    /// reserve ratio of the total deposits and the reserve floor, less what has been released.
+    pub fn reserve(&self) -> Result<Amount, DAOError> {
+        // The reserve is distributed to the members on dissolution.
+        if self.dissolution.is_some() {
+            return Ok(Amount::zero());
+        }
+        let by_ratio = u128::from(self.total_deposited.micro_ccd())
+            * u128::from(self.config.reserve_ratio_bps)
+            / u128::from(MAX_BPS);
+        let required = Amount::from_micro_ccd(by_ratio as u64).max(self.config.reserve_floor);
+        Ok(required
+            .checked_sub(self.reserve_released)
+            .unwrap_or_else(Amount::zero))
+    }
+
+    /// Sum of the refunds owed to contracts.
+    pub fn owed_total(&self) -> Result<Amount, DAOError> {
+        checked_sum_amounts(self.owed.iter().map(|(_, amount)| *amount))
+    }
+
+    /// Part of the balance that is committed, held, in funding rounds, reserved, accrued as fees,
+    /// owed to contracts or left to be claimed on dissolution.
+    pub fn locked(&self) -> Result<Amount, DAOError> {
+        let reserved = checked_add_amounts(self.committed()?, self.held()?)?;
+        let reserved = checked_add_amounts(reserved, self.in_rounds()?)?;
+        let reserved = checked_add_amounts(reserved, self.reserve()?)?;
+        let reserved = checked_add_amounts(reserved, self.fees)?;
+        let reserved = checked_add_amounts(reserved, self.owed_total()?)?;
+        let reserved = match &self.dissolution {
+            Some(dissolution) => checked_add_amounts(reserved, dissolution.unclaimed())?,
+            None => reserved,
+        };
+        Ok(reserved)
+    }
+
+    /// Part of the balance that is not locked.
+    pub fn free_balance(&self, balance: Amount) -> Result<Amount, DAOError> {
+        Ok(balance
+            .checked_sub(self.locked()?)
+            .unwrap_or_else(Amount::zero))
+    }
+}
+
+/// Name of the registry entrypoint invoked on config updates.
+const REFRESH_ENTRYPOINT: EntrypointName<'static> = EntrypointName::new_unchecked("refresh");
+
+/// Name of the entrypoint invoked on contracts receiving CCD from the DAO.
+const PAYOUT_ENTRYPOINT: EntrypointName<'static> = EntrypointName::new_unchecked("receive_payout");
+
+/// Pays an amount out to an account, or to a contract through its [`PAYOUT_ENTRYPOINT`].
+fn pay_out(host: &mut Host<DAOState>, to: &Address, amount: Amount) -> ReceiveResult<()> {
+    match to {
+        Address::Account(account) => host.invoke_transfer(account, amount)?,
+        Address::Contract(contract) => {
+            host.invoke_contract(contract, &(), PAYOUT_ENTRYPOINT, amount)?;
+        }
+    }
+    Ok(())
+}
+
+/// Refunds an amount to an account, or to a contract through its [`PAYOUT_ENTRYPOINT`]. Refunds
+/// that a contract fails to receive are owed to it instead, so that it can't block the caller.
+fn pay_refund(
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    to: &Address,
+    amount: Amount,
+) -> ReceiveResult<()> {
+    let contract = match to {
+        Address::Account(account) => return Ok(host.invoke_transfer(account, amount)?),
+        Address::Contract(contract) => *contract,
+    };
+
+    if host
+        .invoke_contract(&contract, &(), PAYOUT_ENTRYPOINT, amount)
+        .is_ok()
+    {
+        return Ok(());
+    }
+
+    let owed = &mut host.state_mut().owed;
+    match owed.iter_mut().find(|(address, _)| *address == contract) {
+        Some((_, owed)) => *owed = checked_add_amounts(*owed, amount)?,
+        None => owed.push((contract, amount)),
+    }
+
+    logger.log(&DAOEvent::RefundDeferred {
+        recipient: contract,
+        amount,
+    })?;
+
+    Ok(())
+}
+
+/// Checks that the sender is one of the guardians of the DAO.
+fn ensure_guardian(ctx: &ReceiveContext, state: &DAOState) -> Result<(), DAOError> {
+    match ctx.sender() {
+        Address::Account(account) if state.guardians.contains(&account) => Ok(()),
+        _ => Err(DAOError::Unauthorized),
+    }
+}
+
+/// Checks that the DAO has not been dissolved.
+fn ensure_not_dissolved(state: &DAOState) -> Result<(), DAOError> {
+    if state.dissolution.is_some() {
+        return Err(DAOError::Dissolved);
+    }
+    Ok(())
+}
+
+/// Adds two amounts, failing with [`DAOError::Overflow`] instead of overflowing.
+fn checked_add_amounts(a: Amount, b: Amount) -> Result<Amount, DAOError> {
+    a.checked_add(b).ok_or(DAOError::Overflow)
+}
+
+/// Sums amounts, failing with [`DAOError::Overflow`] instead of overflowing.
+fn checked_sum_amounts(mut amounts: impl Iterator<Item = Amount>) -> Result<Amount, DAOError> {
+    amounts.try_fold(Amount::zero(), checked_add_amounts)
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting above the root.
+    let mut x = n;
+    let mut y = (x + n / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Sums votes or voting power, failing with [`DAOError::Overflow`] instead of overflowing.
+fn checked_sum(mut values: impl Iterator<Item = u64>) -> Result<u64, DAOError> {
+    values.try_fold(0u64, |acc, v| acc.checked_add(v).ok_or(DAOError::Overflow))
+}
+
+/// Input for the initialization of [`DAO`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct InitParams {
+    pub name: String,
+    pub config: DAOConfig,
+}
+
+/// Parameters of the DAO, provided on initialization.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct DAOConfig {
+    /// Bond that has to be attached when creating a proposal.
+    pub proposal_bond: Amount,
+    /// Duration for which a proposal is open for voting.
+    pub voting_period: Duration,
+    /// Votes an expired proposal needs for its bond to be refunded.
+    pub min_support: u64,
+    /// Duration after collection within which the proposer has to submit an impact report.
+    pub report_period: Duration,
+    /// Votes a dispute needs to be upheld.
+    pub dispute_quorum: u64,
+    /// Deposit required for becoming a member, net of the operations fee.
+    pub min_deposit: Amount,
+    /// Membership tiers, attained through cumulative deposits.
+    pub tiers: Vec<Tier>,
+    /// Tier required for creating proposals above a certain amount.
+    pub proposal_gate: Option<ProposalGate>,
+    /// Payouts above this amount have to be confirmed by the signers.
+    pub payout_limit: Option<Amount>,
+    /// Votes a governance proposal needs to be executed.
+    pub governance_threshold: u64,
+    /// Part of the total deposits kept as reserve, in basis points.
+    pub reserve_ratio_bps: u16,
+    /// Minimum reserve, regardless of the deposits.
+    pub reserve_floor: Amount,
+    /// Whether donation receipts can be transferred.
+    pub receipts_transferable: bool,
+    /// Operations fee deducted from deposits, in basis points.
+    pub fee_bps: u16,
+    /// Account the operations fees are paid to; required when charging a fee.
+    pub operations_account: Option<AccountAddress>,
+    /// Enables commit-reveal voting: votes are committed during the voting period and
+    /// revealed during this period after it.
+    pub reveal_period: Option<Duration>,
+}
+
+impl DAOConfig {
+    /// Checks the consistency of the parameters.
+    pub fn validate(&self) -> Result<(), DAOError> {
+        // A fee of the full deposit would leave nothing to the DAO.
+        if self.reserve_ratio_bps > MAX_BPS || self.fee_bps >= MAX_BPS {
+            return Err(DAOError::InvalidConfig);
+        }
+        if self.fee_bps > 0 && self.operations_account.is_none() {
+            return Err(DAOError::InvalidConfig);
+        }
+        // Without thresholds a single vote would pass any governance proposal or dispute.
+        if self.governance_threshold == 0 || self.dispute_quorum == 0 {
+            return Err(DAOError::InvalidConfig);
+        }
+        if let Some(gate) = &self.proposal_gate {
+            if self.tier(&gate.tier).is_none() {
+                return Err(DAOError::InvalidConfig);
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up a tier by its name.
+    pub fn tier(&self, name: &str) -> Option<&Tier> {
+        self.tiers.iter().find(|t| t.name == name)
+    }
+
+    /// The highest tier attained with the given cumulative deposit.
+    pub fn tier_of(&self, deposited: Amount) -> Option<&Tier> {
+        self.tiers
+            .iter()
+            .filter(|t| deposited >= t.min_deposited)
+            .max_by_key(|t| t.min_deposited)
+    }
+}
+
+/// Membership tier, e.g. Supporter or Patron.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Tier {
+    pub name: String,
+    /// Cumulative deposit required for the tier.
+    pub min_deposited: Amount,
+}
+
+/// Restricts proposals above an amount to members of a tier or higher.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ProposalGate {
+    /// Proposals above this amount are gated.
+    pub amount: Amount,
+    /// Name of the tier required for gated proposals.
+    pub tier: String,
+}
+
+/// Granted to anyone who deposits CCD into the DAO.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Member {
+    /// Voting power left to the member.
+    pub power: u64,
+    /// Total amount deposited by the member.
+    pub deposited: Amount,
 }
 
 /// Can be proposed by anyone, membership in the DAO is not mandatory.
 #[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
 pub struct Proposal {
     /// The one who has proposed the charity.
-    pub proposer: AccountAddress,
+    pub proposer: Address,
     /// Description regarding the charity.
     pub description: String,
     /// Threshold amount required for the charity.
@@ -23,8 +375,298 @@ pub struct Proposal {
     /// Votes attained by the proposal.
     pub votes: u64,
     /// Those who have voted for the charity.
-    pub contributers: Vec<(AccountAddress, u64)>,
+    pub contributers: Vec<(Address, u64)>,
     pub status: Status,
+    /// Bond locked by the proposer on creation.
+    pub bond: Amount,
+    /// Time after which voting is closed.
+    pub deadline: Timestamp,
+    /// Those who have donated directly to the charity.
+    pub donations: Vec<(Address, Amount)>,
+    /// Time until which an impact report is due, set on collection.
+    pub report_deadline: Option<Timestamp>,
+    /// Impact report submitted by the proposer after collection.
+    pub report: Option<MetadataUrl>,
+    /// Number of amendments made to the proposal.
+    pub revision: u32,
+    /// Hash of the description before the latest amendment.
+    pub previous_description_hash: Option<HashSha2256>,
+    /// Signers who have confirmed the payout.
+    pub confirmations: Vec<AccountAddress>,
+    pub kind: ProposalKind,
+    /// EUR amount of proposals denominated in EUR; the CCD amount is converted from it on approval
+    /// and payout.
+    pub euro: Option<EuroAmount>,
+    /// End of the reveal phase of commit-reveal proposals.
+    pub reveal_deadline: Option<Timestamp>,
+    /// Vote commitments yet to be revealed.
+    pub commitments: Vec<(Address, HashSha2256)>,
+    /// Revision of the config the proposal was created against.
+    pub config_revision: u32,
+}
+
+impl Proposal {
+    /// Donations earmarked for the proposal.
+    pub fn earmarked(&self) -> Result<Amount, DAOError> {
+        checked_sum_amounts(self.donations.iter().map(|(_, amount)| *amount))
+    }
+
+    /// Funding attained by the proposal through votes and earmarked donations.
+    pub fn funding(&self) -> Result<u64, DAOError> {
+        self.votes
+            .checked_add(self.earmarked()?.micro_ccd())
+            .ok_or(DAOError::Overflow)
+    }
+
+    /// Checks that the votes of the proposal equal the sum of the votes of its contributers.
+    pub fn check_votes(&self) -> Result<(), DAOError> {
+        let contributed = checked_sum(self.contributers.iter().map(|(_, votes)| *votes))?;
+        if contributed != self.votes {
+            return Err(DAOError::VotesMismatch);
+        }
+        Ok(())
+    }
+
+    /// Takes back the votes beyond the amount from the latest contributers, returning the votes
+    /// taken from each of them.
+    pub fn trim_surplus(&mut self) -> Result<Vec<(Address, u64)>, DAOError> {
+        let mut surplus = self
+            .funding()?
+            .saturating_sub(self.amount.micro_ccd())
+            .min(self.votes);
+        let mut trimmed = vec![];
+        for (voter, votes) in self.contributers.iter_mut().rev() {
+            if surplus == 0 {
+                break;
+            }
+            let taken = surplus.min(*votes);
+            *votes -= taken;
+            surplus -= taken;
+            self.votes -= taken;
+            trimmed.push((*voter, taken));
+        }
+        self.contributers.retain(|(_, votes)| *votes > 0);
+        Ok(trimmed)
+    }
+
+    /// Takes back the donations beyond the amount from the latest donors, returning the
+    /// amounts taken from each of them.
+    pub fn trim_donations(&mut self) -> Result<Vec<(Address, Amount)>, DAOError> {
+        let mut surplus = self.funding()?.saturating_sub(self.amount.micro_ccd());
+        let mut trimmed = vec![];
+        for (donor, donated) in self.donations.iter_mut().rev() {
+            if surplus == 0 {
+                break;
+            }
+            let taken = surplus.min(donated.micro_ccd());
+            *donated = Amount::from_micro_ccd(donated.micro_ccd() - taken);
+            surplus -= taken;
+            trimmed.push((*donor, Amount::from_micro_ccd(taken)));
+        }
+        self.donations
+            .retain(|(_, donated)| *donated > Amount::zero());
+        Ok(trimmed)
+    }
+
+    /// Checks whether the impact report of a collected proposal is overdue at the given time.
+    pub fn report_overdue(&self, now: Timestamp) -> bool {
+        self.status == Status::Collected && self.report_deadline.is_some_and(|d| now >= d)
+    }
+}
+
+/// Amount of a proposal denominated in EUR.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct EuroAmount {
+    pub euro_cents: u64,
+    /// Maximum deviation of the payout from the CCD amount at approval, in basis points.
+    pub max_slippage_bps: u16,
+}
+
+impl EuroAmount {
+    /// Converts the EUR amount to CCD at the given exchange rates.
+    pub fn to_ccd(&self, rates: &ExchangeRates) -> Result<Amount, DAOError> {
+        let rate = rates.micro_ccd_per_euro;
+        let micro_ccd = u128::from(self.euro_cents) * u128::from(rate.numerator())
+            / (u128::from(rate.denominator()) * 100);
+        Ok(Amount::from_micro_ccd(
+            u64::try_from(micro_ccd).map_err(|_| DAOError::Overflow)?,
+        ))
+    }
+
+    /// Checks whether the payout stays within the slippage bound of the amount at approval.
+    pub fn check_slippage(&self, approved: Amount, payout: Amount) -> Result<(), DAOError> {
+        let bound = u128::from(approved.micro_ccd()) * u128::from(self.max_slippage_bps)
+            / u128::from(MAX_BPS);
+        if u128::from(approved.micro_ccd().abs_diff(payout.micro_ccd())) > bound {
+            return Err(DAOError::SlippageExceeded);
+        }
+        Ok(())
+    }
+}
+
+/// Converts a CCD amount to euro cents at the given exchange rates.
+fn to_euro_cents(amount: Amount, rates: &ExchangeRates) -> Result<u64, DAOError> {
+    let rate = rates.micro_ccd_per_euro;
+    let euro_cents = u128::from(amount.micro_ccd()) * u128::from(rate.denominator()) * 100
+        / u128::from(rate.numerator());
+    u64::try_from(euro_cents).map_err(|_| DAOError::Overflow)
+}
+
+/// Commitment of a voter to votes on a proposal, hiding the votes until revealed with the salt.
+pub fn vote_commitment(
+    crypto_primitives: &CryptoPrimitives,
+    voter: Address,
+    proposal_id: u64,
+    votes: u64,
+    salt: &[u8; 32],
+) -> HashSha2256 {
+    crypto_primitives.hash_sha2_256(&to_bytes(&(proposal_id, voter, votes, salt)))
+}
+
+/// Kind of a proposal.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// Requests funding for a charity.
+    Funding,
+    /// Changes the rules of the DAO once approved.
+    Governance(GovernanceAction),
+}
+
+/// Action executed by an approved governance proposal.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum GovernanceAction {
+    /// Replaces the parameters of the DAO.
+    UpdateConfig(DAOConfig),
+    /// Replaces the signers confirming large payouts.
+    SetSigners(Signers),
+    /// Invokes another contract on behalf of the DAO.
+    Invoke(ContractCall),
+    /// Releases part of the reserve in an emergency.
+    ReleaseReserve(ReserveRelease),
+    /// Dissolves the DAO, distributing the treasury to the members.
+    Dissolve,
+    /// Replaces the guardians vetoing proposals and setting the signers and implementors.
+    SetGuardians(Vec<AccountAddress>),
+}
+
+/// Treasury set aside on dissolution, claimed by the members pro rata to their deposits.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Dissolution {
+    /// Amount distributed to the members.
+    pub pool: Amount,
+    /// Sum of the deposits of all members at dissolution.
+    pub deposits: Amount,
+    /// Amount claimed so far.
+    pub claimed: Amount,
+    /// Sum of the deposits of the members who have claimed their share.
+    pub claimed_deposits: Amount,
+    /// Members who have claimed their share.
+    pub claimants: Vec<Address>,
+}
+
+impl Dissolution {
+    /// Share of the pool of a member with the given deposits. The last claimant receives what is
+    /// left, so that no dust remains from rounding down.
+    pub fn share_of(&self, deposited: Amount) -> Result<Amount, DAOError> {
+        if checked_add_amounts(self.claimed_deposits, deposited)? >= self.deposits {
+            return Ok(self.unclaimed());
+        }
+        let share = (u128::from(self.pool.micro_ccd()) * u128::from(deposited.micro_ccd()))
+            .checked_div(u128::from(self.deposits.micro_ccd()))
+            .unwrap_or(0);
+        Ok(Amount::from_micro_ccd(share as u64))
+    }
+
+    /// Part of the pool not claimed yet.
+    pub fn unclaimed(&self) -> Amount {
+        self.pool
+            .checked_sub(self.claimed)
+            .unwrap_or_else(Amount::zero)
+    }
+}
+
+/// Transfer out of the reserve executed by an emergency proposal.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ReserveRelease {
+    pub recipient: AccountAddress,
+    pub amount: Amount,
+}
+
+/// Contract invocation executed by a governance proposal.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ContractCall {
+    pub target: ContractAddress,
+    pub entrypoint: OwnedEntrypointName,
+    pub parameter: OwnedParameter,
+    /// Amount sent from the treasury along with the invocation.
+    pub amount: Amount,
+}
+
+/// Reason for a failed release of the reserve.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum ReleaseFailure {
+    /// The amount exceeds what is left of the reserve.
+    ExceedsReserve,
+    /// The balance of the DAO doesn't cover the amount.
+    AmountTooLarge,
+    /// The recipient account doesn't exist.
+    MissingAccount,
+}
+
+impl From<TransferError> for ReleaseFailure {
+    fn from(err: TransferError) -> Self {
+        match err {
+            TransferError::AmountTooLarge => ReleaseFailure::AmountTooLarge,
+            TransferError::MissingAccount => ReleaseFailure::MissingAccount,
+        }
+    }
+}
+
+/// Reason for a failed contract invocation, mirroring [`CallContractError`].
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum CallFailure {
+    /// The free balance of the DAO doesn't cover the amount.
+    InsufficientBalance,
+    AmountTooLarge,
+    MissingAccount,
+    MissingContract,
+    MissingEntrypoint,
+    MessageFailed,
+    LogicReject {
+        reason: i32,
+    },
+    Trap,
+}
+
+impl<T> From<CallContractError<T>> for CallFailure {
+    fn from(err: CallContractError<T>) -> Self {
+        match err {
+            CallContractError::AmountTooLarge => CallFailure::AmountTooLarge,
+            CallContractError::MissingAccount => CallFailure::MissingAccount,
+            CallContractError::MissingContract => CallFailure::MissingContract,
+            CallContractError::MissingEntrypoint => CallFailure::MissingEntrypoint,
+            CallContractError::MessageFailed => CallFailure::MessageFailed,
+            CallContractError::LogicReject { reason, .. } => CallFailure::LogicReject { reason },
+            CallContractError::Trap => CallFailure::Trap,
+        }
+    }
+}
+
+impl GovernanceAction {
+    /// Checks whether the action can be executed.
+    pub fn validate(&self) -> Result<(), DAOError> {
+        match self {
+            GovernanceAction::UpdateConfig(config) => config.validate(),
+            GovernanceAction::SetSigners(signers) => signers.validate(),
+            GovernanceAction::Invoke(_) => Ok(()),
+            GovernanceAction::ReleaseReserve(release) if release.amount == Amount::zero() => {
+                Err(DAOError::ZeroAmount)
+            }
+            GovernanceAction::ReleaseReserve(_)
+            | GovernanceAction::Dissolve
+            | GovernanceAction::SetGuardians(_) => Ok(()),
+        }
+    }
 }
 
 /// Status of a proposal.
@@ -33,6 +675,194 @@ pub enum Status {
     Active,
     Approved,
     Collected,
+    Reported,
+    Executed,
+    Expired,
+    Vetoed,
+    Cancelled,
+}
+
+/// Raised by a member against a collected proposal, decided by the votes of the members.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Dispute {
+    /// The disputed proposal.
+    pub proposal_id: u64,
+    /// The member who has flagged the proposal.
+    pub flagger: Address,
+    /// Reason for flagging the proposal.
+    pub reason: String,
+    /// Votes in favour of the dispute.
+    pub votes: u64,
+    /// Those who have voted in favour of the dispute.
+    pub contributers: Vec<(Address, u64)>,
+    pub status: DisputeStatus,
+    /// Time after which voting is closed.
+    pub deadline: Timestamp,
+}
+
+/// Status of a dispute.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Rejected,
+}
+
+/// Quadratic funding round, in which contributions to proposals are matched from the pool of a sponsor.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Round {
+    /// The one who has deposited the matching pool.
+    pub sponsor: Address,
+    /// Matching pool of the round.
+    pub pool: Amount,
+    /// Time after which contributions are closed.
+    pub deadline: Timestamp,
+    /// Contributions to proposals, by proposal and contributor.
+    pub contributions: Vec<Contribution>,
+    pub status: RoundStatus,
+    /// Payouts of the proposals, computed when the round is closed.
+    pub payouts: Vec<RoundPayout>,
+}
+
+impl Round {
+    /// Amount the DAO holds for the round.
+    pub fn reserved(&self) -> Result<Amount, DAOError> {
+        match self.status {
+            RoundStatus::Open => checked_add_amounts(
+                self.pool,
+                checked_sum_amounts(self.contributions.iter().map(|c| c.amount))?,
+            ),
+            RoundStatus::Closed => {
+                let mut unclaimed = Amount::zero();
+                for p in self.payouts.iter().filter(|p| !p.claimed) {
+                    unclaimed = checked_add_amounts(unclaimed, p.contributed)?;
+                    unclaimed = checked_add_amounts(unclaimed, p.matched)?;
+                }
+                Ok(unclaimed)
+            }
+        }
+    }
+
+    /// Computes the quadratic funding match of every proposal, returning the payouts and
+    /// the part of the pool which is left unmatched due to rounding.
+    fn compute_matches(&self, proposals: &[u64]) -> Result<(Vec<RoundPayout>, Amount), DAOError> {
+        let mut scores = Vec::with_capacity(proposals.len());
+        for proposal_id in proposals.iter() {
+            let mut sqrt_sum: u128 = 0;
+            let mut contributed = Amount::zero();
+            for c in self
+                .contributions
+                .iter()
+                .filter(|c| c.proposal_id == *proposal_id)
+            {
+                sqrt_sum += u128::from(isqrt(c.amount.micro_ccd()));
+                contributed = checked_add_amounts(contributed, c.amount)?;
+            }
+            // (sum of square roots)^2 minus the contributions themselves.
+            let score = sqrt_sum
+                .checked_mul(sqrt_sum)
+                .ok_or(DAOError::Overflow)?
+                .saturating_sub(u128::from(contributed.micro_ccd()));
+            scores.push((*proposal_id, contributed, score));
+        }
+
+        let total: u128 = scores.iter().map(|(_, _, score)| *score).sum();
+        let mut payouts = Vec::with_capacity(scores.len());
+        let mut matched_total = Amount::zero();
+        for (proposal_id, contributed, score) in scores {
+            // Nothing is matched if no proposal has a positive score.
+            let matched = u128::from(self.pool.micro_ccd())
+                .checked_mul(score)
+                .ok_or(DAOError::Overflow)?
+                .checked_div(total)
+                .unwrap_or(0);
+            let matched = Amount::from_micro_ccd(matched as u64);
+            matched_total = checked_add_amounts(matched_total, matched)?;
+            payouts.push(RoundPayout {
+                proposal_id,
+                contributed,
+                matched,
+                claimed: false,
+            });
+        }
+
+        let unmatched = self
+            .pool
+            .checked_sub(matched_total)
+            .ok_or(DAOError::Overflow)?;
+        Ok((payouts, unmatched))
+    }
+}
+
+/// Contribution to a proposal in a funding round.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Contribution {
+    pub proposal_id: u64,
+    pub contributor: Address,
+    pub amount: Amount,
+}
+
+/// Status of a funding round.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub enum RoundStatus {
+    Open,
+    Closed,
+}
+
+/// Payout of a proposal in a closed funding round.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RoundPayout {
+    pub proposal_id: u64,
+    /// Contributions made to the proposal in the round.
+    pub contributed: Amount,
+    /// Match from the pool.
+    pub matched: Amount,
+    /// Whether the proposer has claimed the payout.
+    pub claimed: bool,
+}
+
+/// Donation receipt, minted as a CIS-2 token to the donor on every deposit.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Receipt {
+    pub owner: Address,
+    /// Amount deposited.
+    pub amount: Amount,
+    /// Time of the deposit.
+    pub timestamp: Timestamp,
+}
+
+impl Receipt {
+    /// Metadata of the receipt, embedded as a percent-encoded JSON data URL.
+    pub fn metadata_url(&self, token_id: ContractTokenId, dao: ContractAddress) -> MetadataUrl {
+        let json = format!(
+            "{{\"name\":\"Donation receipt {}\",\"attributes\":[\
+             {{\"type\":\"string\",\"name\":\"amount\",\"value\":\"{}\"}},\
+             {{\"type\":\"string\",\"name\":\"timestamp\",\"value\":\"{}\"}},\
+             {{\"type\":\"string\",\"name\":\"dao\",\"value\":\"<{},{}>\"}}]}}",
+            token_id.0,
+            self.amount.micro_ccd(),
+            self.timestamp.timestamp_millis(),
+            dao.index,
+            dao.subindex,
+        );
+        MetadataUrl {
+            url: format!("data:application/json,{}", percent_encode(&json)),
+            hash: None,
+        }
+    }
+}
+
+/// Percent-encodes all but the unreserved characters of a string, for use in a URL.
+fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
 }
 
 /// Input for [`DAO.create_proposal`].
@@ -42,7 +872,29 @@ pub struct ProposalInput {
     pub amount: Amount,
 }
 
-/// Input for [`DAO.vote`].
+/// Input for [`DAO.create_euro_proposal`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct EuroProposalInput {
+    pub description: String,
+    pub amount: EuroAmount,
+}
+
+/// Input for [`DAO.create_governance_proposal`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct GovernanceInput {
+    pub description: String,
+    pub action: GovernanceAction,
+}
+
+/// Input for [`DAO.amend_proposal`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct AmendInput {
+    pub proposal_id: u64,
+    pub description: String,
+    pub amount: Amount,
+}
+
+/// Input for [`DAO.vote`] and [`DAO.renounce`], and items of their batches.
 #[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
 pub struct VoteInput {
     pub proposal_id: u64,
@@ -55,25 +907,269 @@ pub struct WithdrawInput {
     pub proposal_id: u64,
 }
 
-/// Input for [`DAO.get_power`].
+/// Input for [`DAO.commit_vote`].
 #[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
-pub struct AddressInput {
-    pub address: AccountAddress,
+pub struct CommitInput {
+    pub proposal_id: u64,
+    pub commitment: HashSha2256,
 }
 
-///  Smart contract errors.
-#[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
-pub enum DAOError {
-    /// Failed parsing the parameter.
-    #[from(ParseError)]
-    ParseParams,
-    Unauthorized,
-    ProposalNotFound,
-    NotApproved,
-    AlreadyApproved,
-    InsufficientBalance,
-    AmountCollected,
-}
+/// Input for [`DAO.reveal_vote`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RevealInput {
+    pub proposal_id: u64,
+    pub votes: u64,
+    pub salt: [u8; 32],
+}
+
+/// Input for [`DAO.vote_commitment`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct CommitmentInput {
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub votes: u64,
+    pub salt: [u8; 32],
+}
+
+/// Input for [`DAO.claim_refund`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ClaimRefundInput {
+    /// Payable entrypoint of the sender the refund is paid to.
+    pub entrypoint: OwnedEntrypointName,
+}
+
+/// Input for [`DAO.veto`], [`DAO.expire`], [`DAO.cancel_proposal`], [`DAO.donate_to_proposal`],
+/// [`DAO.confirm_payout`], [`DAO.revoke_confirmation`] and [`DAO.tally`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ProposalIdInput {
+    pub proposal_id: u64,
+}
+
+/// Input for [`DAO.submit_report`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ReportInput {
+    pub proposal_id: u64,
+    /// Location and hash of the impact report.
+    pub report: MetadataUrl,
+}
+
+/// Input for [`DAO.flag_proposal`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct FlagInput {
+    pub proposal_id: u64,
+    pub reason: String,
+}
+
+/// Input for [`DAO.vote_dispute`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct DisputeVoteInput {
+    pub dispute_id: u64,
+    pub votes: u64,
+}
+
+/// Input for [`DAO.close_dispute`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct DisputeIdInput {
+    pub dispute_id: u64,
+}
+
+/// Input for [`DAO.create_round`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RoundInput {
+    /// Duration for which the round accepts contributions.
+    pub duration: Duration,
+}
+
+/// Input for [`DAO.contribute`], [`DAO.claim_match`] and [`DAO.release_match`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RoundProposalInput {
+    pub round_id: u64,
+    pub proposal_id: u64,
+}
+
+/// Input for [`DAO.close_round`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RoundIdInput {
+    pub round_id: u64,
+}
+
+/// Input for [`DAO.set_signers`] and return value of [`DAO.signers`].
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct Signers {
+    pub signers: Vec<AccountAddress>,
+    /// Confirmations required for payouts above the payout limit.
+    pub required: u32,
+}
+
+impl Signers {
+    /// Checks that at least one, and at most every signer has to confirm.
+    pub fn validate(&self) -> Result<(), DAOError> {
+        if self.signers.is_empty() != (self.required == 0)
+            || self.required as usize > self.signers.len()
+        {
+            return Err(DAOError::InvalidConfig);
+        }
+        Ok(())
+    }
+}
+
+/// Input for [`DAO.set_implementors`].
+#[derive(Debug, Serialize, SchemaType)]
+pub struct SetImplementorsInput {
+    /// The standard identifier to map to implementors.
+    pub id: StandardIdentifierOwned,
+    /// The contracts implementing the standard; an empty list removes the mapping.
+    pub implementors: Vec<ContractAddress>,
+}
+
+/// Return value of [`DAO.info`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct DAOInfo {
+    pub name: String,
+    pub config: DAOConfig,
+    pub origin: AccountAddress,
+}
+
+/// Return value of [`DAO.treasury`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct TreasuryView {
+    /// Balance of the contract.
+    pub balance: Amount,
+    /// Total amount ever deposited through [`DAO.insert`].
+    pub total_deposited: Amount,
+    /// Voting power that members have not spent yet.
+    pub outstanding_power: u64,
+    /// Amount committed to approved proposals that are yet to be collected.
+    pub committed: Amount,
+    /// Bonds and earmarked donations held for active proposals.
+    pub held: Amount,
+    /// Matching pools and contributions of funding rounds, including unclaimed payouts.
+    pub in_rounds: Amount,
+    /// Total amount paid out to collected proposals and funding round payouts.
+    pub disbursed: Amount,
+    /// Emergency fund, only released through emergency proposals.
+    pub reserve: Amount,
+    /// Operations fees not collected yet.
+    pub fees: Amount,
+    /// Refunds owed to contracts that failed to receive them.
+    pub owed: Amount,
+    /// Part of the balance that is neither committed, held, in funding rounds, reserved, accrued
+    /// as fees, owed to contracts nor left to be claimed on dissolution.
+    pub free_balance: Amount,
+}
+
+/// Return value of [`DAO.get_proposal_value`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct ProposalValue {
+    /// CCD amount of the proposal, as of its creation, last approval or payout.
+    pub amount: Amount,
+    /// Value of the proposal in euro cents; converted from the amount if denominated in CCD.
+    pub euro_cents: u64,
+    /// CCD equivalent at the current exchange rate.
+    pub current: Amount,
+}
+
+/// Return value of [`DAO.get_member`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct MemberView {
+    /// Voting power left to the member.
+    pub power: u64,
+    /// Total amount deposited by the member.
+    pub deposited: Amount,
+    /// Votes the member has on active proposals.
+    pub active_votes: u64,
+    /// Name of the tier attained by the member.
+    pub tier: Option<String>,
+}
+
+/// Return value of [`DAO.track_record`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct TrackRecord {
+    /// Proposals created by the account.
+    pub proposed: u64,
+    /// Proposals that were collected, whether reported or not.
+    pub collected: u64,
+    /// Collected proposals with an impact report.
+    pub reported: u64,
+    /// Collected proposals whose impact report is overdue.
+    pub overdue: u64,
+}
+
+/// Input for [`DAO.get_power`], [`DAO.get_member`], [`DAO.votes_of`], [`DAO.track_record`] and [`DAO.is_blocked`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct AddressInput {
+    pub address: Address,
+}
+
+///  Smart contract errors.
+#[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
+pub enum DAOError {
+    /// Failed parsing the parameter.
+    #[from(ParseError)]
+    ParseParams,
+    Unauthorized,
+    ProposalNotFound,
+    NotApproved,
+    AlreadyApproved,
+    InsufficientBalance,
+    AmountCollected,
+    InvalidBond,
+    VotingClosed,
+    VotingOpen,
+    ProposalClosed,
+    NotAVoter,
+    VotesMismatch,
+    Overflow,
+    ZeroAmount,
+    NotAMember,
+    NotCollected,
+    AlreadyReported,
+    ReportsOverdue,
+    DisputeNotFound,
+    AlreadyDisputed,
+    DisputeClosed,
+    Blocked,
+    InvalidConfig,
+    BelowMinDeposit,
+    TierRequired,
+    BelowDonations,
+    RoundNotFound,
+    RoundClosed,
+    AlreadyClaimed,
+    AlreadyConfirmed,
+    NotConfirmed,
+    ConfirmationsMissing,
+    NotFunding,
+    NotGovernance,
+    ReserveBreached,
+    NonTransferable,
+    NoOperationsAccount,
+    SlippageExceeded,
+    EuroDenominated,
+    CommitRevealRequired,
+    NotCommitReveal,
+    CommitmentMismatch,
+    Dissolved,
+    NotDissolved,
+    NothingOwed,
+    /// Failed logging an event.
+    #[from(LogError)]
+    LogFailed,
+    /// A contract invoked by the DAO rejected the call, e.g. a token transfer or a payout.
+    InvokeContractError,
+}
+
+impl<T> From<CallContractError<T>> for DAOError {
+    fn from(_err: CallContractError<T>) -> Self {
+        DAOError::InvokeContractError
+    }
+}
+
+impl From<DAOError> for ContractError {
+    fn from(err: DAOError) -> Self {
+        Cis2Error::Custom(err)
+    }
+}
 
 /// Events emitted from DAO contract.
 #[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
@@ -85,281 +1181,3074 @@ pub enum DAOEvent {
     },
     Voted {
         proposal_id: u64,
-        voter: AccountAddress,
+        voter: Address,
+        /// Votes counted towards the proposal.
+        accepted: u64,
+        /// Votes beyond the threshold, left to the voter.
+        returned: u64,
         total_votes: u64,
     },
+    /// Votes beyond the amount of a proposal at its approval, returned to the voter.
+    VotesReturned {
+        proposal_id: u64,
+        voter: Address,
+        votes: u64,
+    },
+    VoteCommitted {
+        proposal_id: u64,
+        voter: Address,
+    },
     Renounced {
         proposal_id: u64,
-        voter: AccountAddress,
+        voter: Address,
+        total_votes: u64,
+    },
+    Vetoed {
+        proposal_id: u64,
+    },
+    Expired {
+        proposal_id: u64,
+    },
+    BondRefunded {
+        proposal_id: u64,
+        proposer: Address,
+        amount: Amount,
+    },
+    BondForfeited {
+        proposal_id: u64,
+        amount: Amount,
+    },
+    Cancelled {
+        proposal_id: u64,
+    },
+    Donated {
+        proposal_id: u64,
+        donor: Address,
+        amount: Amount,
+    },
+    DonationRefunded {
+        proposal_id: u64,
+        donor: Address,
+        amount: Amount,
+    },
+    Reported {
+        proposal_id: u64,
+        report: MetadataUrl,
+    },
+    Flagged {
+        dispute_id: u64,
+        proposal_id: u64,
+        flagger: Address,
+    },
+    DisputeVoted {
+        dispute_id: u64,
+        voter: Address,
         total_votes: u64,
     },
+    DisputeUpheld {
+        dispute_id: u64,
+        blocked: Address,
+    },
+    DisputeRejected {
+        dispute_id: u64,
+    },
+    Amended {
+        proposal_id: u64,
+        revision: u32,
+        description: String,
+        amount: Amount,
+        /// Whether the votes were returned to the voters due to an increased amount.
+        votes_reset: bool,
+    },
+    RoundCreated {
+        round_id: u64,
+        sponsor: Address,
+        pool: Amount,
+    },
+    Contributed {
+        round_id: u64,
+        proposal_id: u64,
+        contributor: Address,
+        amount: Amount,
+    },
+    Matched {
+        round_id: u64,
+        proposal_id: u64,
+        contributed: Amount,
+        matched: Amount,
+    },
+    RoundClosed {
+        round_id: u64,
+        /// Part of the pool returned to the sponsor.
+        returned: Amount,
+    },
+    MatchClaimed {
+        round_id: u64,
+        proposal_id: u64,
+        proposer: Address,
+        amount: Amount,
+    },
+    MatchReleased {
+        round_id: u64,
+        proposal_id: u64,
+        /// Contributions refunded to the contributors.
+        refunded: Amount,
+        /// Match returned to the sponsor.
+        returned: Amount,
+    },
+    PayoutConfirmed {
+        proposal_id: u64,
+        signer: AccountAddress,
+        confirmations: u32,
+    },
+    ConfirmationRevoked {
+        proposal_id: u64,
+        signer: AccountAddress,
+        confirmations: u32,
+    },
+    Executed {
+        proposal_id: u64,
+    },
+    Invoked {
+        proposal_id: u64,
+        target: ContractAddress,
+        amount: Amount,
+    },
+    InvokeFailed {
+        proposal_id: u64,
+        reason: CallFailure,
+    },
+    ReserveReleased {
+        proposal_id: u64,
+        recipient: AccountAddress,
+        amount: Amount,
+    },
+    ReleaseFailed {
+        proposal_id: u64,
+        reason: ReleaseFailure,
+    },
+    /// The config was updated after the config update proposal was created.
+    ConfigOutdated {
+        proposal_id: u64,
+    },
+    /// The registry rejected refreshing the entry of the DAO after a config update.
+    RegistryNotRefreshed {
+        registry: ContractAddress,
+    },
+    FeeCharged {
+        donor: Address,
+        /// Amount deposited, including the fee.
+        amount: Amount,
+        fee: Amount,
+    },
+    FeesCollected {
+        account: AccountAddress,
+        amount: Amount,
+    },
+    Dissolved {
+        proposal_id: u64,
+        /// Amount distributed to the members.
+        pool: Amount,
+    },
+    ShareClaimed {
+        member: Address,
+        amount: Amount,
+    },
+    RefundDeferred {
+        recipient: ContractAddress,
+        amount: Amount,
+    },
+    RefundClaimed {
+        recipient: ContractAddress,
+        amount: Amount,
+    },
+}
+
+/// Checks whether a proposal is still active.
+fn ensure_active(proposal: &Proposal) -> Result<(), DAOError> {
+    match proposal.status {
+        Status::Active => Ok(()),
+        Status::Approved | Status::Collected | Status::Reported | Status::Executed => {
+            Err(DAOError::AlreadyApproved)
+        }
+        _ => Err(DAOError::ProposalClosed),
+    }
+}
+
+/// Checks whether a proposal is still accepting votes and donations.
+fn ensure_open(ctx: &ReceiveContext, proposal: &Proposal) -> Result<(), DAOError> {
+    ensure_active(proposal)?;
+    if ctx.metadata().slot_time() >= proposal.deadline {
+        return Err(DAOError::VotingClosed);
+    }
+    Ok(())
+}
+
+/// Checks whether a proposal accepts public votes, which commit-reveal proposals don't.
+fn ensure_public(ctx: &ReceiveContext, proposal: &Proposal) -> Result<(), DAOError> {
+    ensure_open(ctx, proposal)?;
+    if proposal.reveal_deadline.is_some() {
+        return Err(DAOError::CommitRevealRequired);
+    }
+    Ok(())
+}
+
+/// Checks whether a commit-reveal proposal is in its reveal phase.
+fn ensure_revealing(ctx: &ReceiveContext, proposal: &Proposal) -> Result<(), DAOError> {
+    ensure_active(proposal)?;
+    let reveal_deadline = proposal.reveal_deadline.ok_or(DAOError::NotCommitReveal)?;
+    let now = ctx.metadata().slot_time();
+    if now < proposal.deadline {
+        return Err(DAOError::VotingOpen);
+    }
+    if now >= reveal_deadline {
+        return Err(DAOError::VotingClosed);
+    }
+    Ok(())
+}
+
+/// Approves a proposal if its funding has reached the threshold. Commit-reveal proposals are
+/// only approved once tallied.
+fn try_approve(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+) -> ReceiveResult<()> {
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+    if proposal.reveal_deadline.is_some() {
+        return Ok(());
+    }
+
+    approve(ctx, host, logger, proposal_id)
+}
+
+/// Approves a proposal if its funding has reached the threshold, refunding the bond to the proposer.
+/// Governance proposals are executed instead, once they reach the governance threshold.
+fn approve(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+) -> ReceiveResult<()> {
+    let governance_threshold = host.state().config.governance_threshold;
+    let config_revision = host.state().config_revision;
+    let rates = host.exchange_rates();
+    let proposal_data = host
+        .state_mut()
+        .proposals
+        .get_mut(proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if let ProposalKind::Governance(_) = proposal_data.1.kind {
+        if proposal_data.1.votes < governance_threshold {
+            return Ok(());
+        }
+
+        // A config update drafted against an older config would revert the updates since; it
+        // expires instead.
+        if let ProposalKind::Governance(GovernanceAction::UpdateConfig(_)) = proposal_data.1.kind {
+            if proposal_data.1.config_revision != config_revision {
+                logger.log(&DAOEvent::ConfigOutdated { proposal_id })?;
+                return expire_proposal(host, logger, proposal_id);
+            }
+        }
+
+        // Votes on governance proposals are not spent.
+        proposal_data.1.status = Status::Approved;
+        let proposal = proposal_data.1.clone();
+        host.state_mut().return_votes(&proposal.contributers)?;
+        settle_bond(host, logger, proposal_id, &proposal, true)?;
+
+        return execute_governance(ctx, host, logger, proposal_id);
+    }
+
+    // Converting proposals denominated in EUR at the current exchange rate.
+    if let Some(euro) = &proposal_data.1.euro {
+        proposal_data.1.amount = euro.to_ccd(&rates)?;
+    }
+
+    // Checking whether the threshold has reached.
+    if proposal_data.1.funding()? < proposal_data.1.amount.micro_ccd() {
+        return Ok(());
+    }
+
+    // The payout is covered by the earmarked donations and the free balance of the DAO.
+    let proposal = proposal_data.1.clone();
+    let balance = host.self_balance();
+    let available =
+        checked_add_amounts(host.state().free_balance(balance)?, proposal.earmarked()?)?;
+    if proposal.amount > available {
+        return Err(DAOError::InsufficientBalance.into());
+    }
+
+    // Approve the proposal if threshold has reached.
+    let state = host.state_mut();
+    let proposal = &mut state.proposals[proposal_id as usize].1;
+    proposal.status = Status::Approved;
+
+    // Votes beyond a lowered or repriced amount are returned rather than spent.
+    let trimmed = proposal.trim_surplus()?;
+    state.return_votes(&trimmed)?;
+    for (voter, votes) in trimmed {
+        logger.log(&DAOEvent::VotesReturned {
+            proposal_id,
+            voter,
+            votes,
+        })?;
+    }
+
+    // Donations beyond the amount are refunded, once the votes are returned.
+    let proposal = &mut state.proposals[proposal_id as usize].1;
+    let refunded = proposal.trim_donations()?;
+    let proposal = proposal.clone();
+    for (donor, amount) in refunded {
+        pay_refund(host, logger, &donor, amount)?;
+        logger.log(&DAOEvent::DonationRefunded {
+            proposal_id,
+            donor,
+            amount,
+        })?;
+    }
+
+    settle_bond(host, logger, proposal_id, &proposal, true)
+}
+
+/// Executes the action of an approved governance proposal. Failed contract invocations and reserve
+/// releases are logged and leave the proposal approved, so that the execution can be retried.
+fn execute_governance(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+) -> ReceiveResult<()> {
+    let state = host.state_mut();
+    let (_, proposal) = &mut state.proposals[proposal_id as usize];
+    // Marking the proposal executed first, so that it can't be executed again by a reentrant call.
+    proposal.status = Status::Executed;
+    let kind = proposal.kind.clone();
+
+    match kind {
+        ProposalKind::Governance(GovernanceAction::UpdateConfig(config)) => {
+            state.config = config;
+            state.config_revision = state
+                .config_revision
+                .checked_add(1)
+                .ok_or(DAOError::Overflow)?;
+
+            // A registry failing to refresh its entry doesn't hold up the update.
+            if let Some(registry) = state.registry {
+                let dao = RegisterInput {
+                    dao: ctx.self_address(),
+                };
+                if host
+                    .invoke_contract(&registry, &dao, REFRESH_ENTRYPOINT, Amount::zero())
+                    .is_err()
+                {
+                    logger.log(&DAOEvent::RegistryNotRefreshed { registry })?;
+                }
+            }
+        }
+        ProposalKind::Governance(GovernanceAction::SetSigners(signers)) => {
+            state.signers = signers;
+        }
+        ProposalKind::Governance(GovernanceAction::SetGuardians(guardians)) => {
+            state.guardians = guardians;
+        }
+        ProposalKind::Governance(GovernanceAction::Dissolve) => {
+            dissolve(host, logger, proposal_id)?;
+        }
+        ProposalKind::Governance(GovernanceAction::Invoke(call)) => {
+            let balance = host.self_balance();
+            let result = if call.amount > host.state().free_balance(balance)? {
+                Err(CallFailure::InsufficientBalance)
+            } else {
+                host.invoke_contract_raw(
+                    &call.target,
+                    call.parameter.as_parameter(),
+                    call.entrypoint.as_entrypoint_name(),
+                    call.amount,
+                )
+                .map_err(CallFailure::from)
+            };
+
+            if let Err(reason) = result {
+                host.state_mut().proposals[proposal_id as usize].1.status = Status::Approved;
+                logger.log(&DAOEvent::InvokeFailed {
+                    proposal_id,
+                    reason,
+                })?;
+                return Ok(());
+            }
+
+            logger.log(&DAOEvent::Invoked {
+                proposal_id,
+                target: call.target,
+                amount: call.amount,
+            })?;
+        }
+        ProposalKind::Governance(GovernanceAction::ReleaseReserve(release)) => {
+            let result = if release.amount > host.state().reserve()? {
+                Err(ReleaseFailure::ExceedsReserve)
+            } else {
+                host.invoke_transfer(&release.recipient, release.amount)
+                    .map_err(ReleaseFailure::from)
+            };
+
+            if let Err(reason) = result {
+                host.state_mut().proposals[proposal_id as usize].1.status = Status::Approved;
+                logger.log(&DAOEvent::ReleaseFailed {
+                    proposal_id,
+                    reason,
+                })?;
+                return Ok(());
+            }
+
+            let state = host.state_mut();
+            state.reserve_released = checked_add_amounts(state.reserve_released, release.amount)?;
+            state.disbursed = checked_add_amounts(state.disbursed, release.amount)?;
+
+            logger.log(&DAOEvent::ReserveReleased {
+                proposal_id,
+                recipient: release.recipient,
+                amount: release.amount,
+            })?;
+        }
+        ProposalKind::Funding => return Err(DAOError::NotFunding.into()),
+    }
+
+    logger.log(&DAOEvent::Executed { proposal_id })?;
+
+    Ok(())
+}
+
+/// Dissolves the DAO: active proposals are cancelled, refunding their bonds, and the treasury that is
+/// not committed, in funding rounds or accrued as fees is set aside for the members to claim.
+fn dissolve(host: &mut Host<DAOState>, logger: &mut Logger, proposal_id: u64) -> ReceiveResult<()> {
+    let active: Vec<u64> = host
+        .state()
+        .proposals
+        .iter()
+        .filter(|(_, p)| p.status == Status::Active)
+        .map(|(id, _)| *id)
+        .collect();
+    for id in active {
+        logger.log(&DAOEvent::Cancelled { proposal_id: id })?;
+        let proposal = close_proposal(host, logger, id, Status::Cancelled)?;
+        settle_bond(host, logger, id, &proposal, true)?;
+    }
+
+    let balance = host.self_balance();
+    let state = host.state_mut();
+    let set_aside = checked_add_amounts(state.committed()?, state.in_rounds()?)?;
+    let set_aside = checked_add_amounts(set_aside, state.fees)?;
+    // Refunds deferred to contracts, including those of the cancelled proposals, stay theirs.
+    let set_aside = checked_add_amounts(set_aside, state.owed_total()?)?;
+    let pool = balance.checked_sub(set_aside).unwrap_or_else(Amount::zero);
+    let deposits = checked_sum_amounts(state.members.iter().map(|(_, m)| m.deposited))?;
+    state.dissolution = Some(Dissolution {
+        pool,
+        deposits,
+        claimed: Amount::zero(),
+        claimed_deposits: Amount::zero(),
+        claimants: vec![],
+    });
+
+    logger.log(&DAOEvent::Dissolved { proposal_id, pool })?;
+
+    Ok(())
+}
+
+/// Function to retry the execution of an approved governance proposal; can be invoked by anyone.
+#[receive(
+    contract = "DAO",
+    name = "execute",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_execute(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.kind == ProposalKind::Funding {
+        return Err(DAOError::NotGovernance.into());
+    }
+    if proposal.status != Status::Approved {
+        return Err(DAOError::NotApproved.into());
+    }
+
+    execute_governance(ctx, host, logger, input.proposal_id)
+}
+
+/// Refunds the bond of a proposal to its proposer, or forfeits it to the DAO.
+fn settle_bond(
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+    proposal: &Proposal,
+    refund: bool,
+) -> ReceiveResult<()> {
+    if proposal.bond == Amount::zero() {
+        return Ok(());
+    }
+
+    if refund {
+        pay_refund(host, logger, &proposal.proposer, proposal.bond)?;
+        logger.log(&DAOEvent::BondRefunded {
+            proposal_id,
+            proposer: proposal.proposer,
+            amount: proposal.bond,
+        })?;
+    } else {
+        logger.log(&DAOEvent::BondForfeited {
+            proposal_id,
+            amount: proposal.bond,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Closes an active proposal with the given status. Voters get their power back and donors
+/// get their earmarked donations refunded; the bond is left to the caller.
+fn close_proposal(
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+    status: Status,
+) -> ReceiveResult<Proposal> {
+    let state = host.state_mut();
+    let proposal_data = state
+        .proposals
+        .get_mut(proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_active(&proposal_data.1)?;
+    proposal_data.1.status = status;
+    let proposal = proposal_data.1.clone();
+
+    // Returning the votes to the voting power of the contributers.
+    state.return_votes(&proposal.contributers)?;
+
+    // Refunding the earmarked donations.
+    for (donor, amount) in proposal.donations.iter() {
+        pay_refund(host, logger, donor, *amount)?;
+        logger.log(&DAOEvent::DonationRefunded {
+            proposal_id,
+            donor: *donor,
+            amount: *amount,
+        })?;
+    }
+
+    Ok(proposal)
+}
+
+/// Checks whether the proposer has attained the tier required for proposing the given amount.
+fn check_gate(state: &DAOState, proposer: Address, amount: Amount) -> Result<(), DAOError> {
+    let gate = match &state.config.proposal_gate {
+        Some(gate) if amount > gate.amount => gate,
+        _ => return Ok(()),
+    };
+
+    let required = state
+        .config
+        .tier(&gate.tier)
+        .ok_or(DAOError::InvalidConfig)?;
+    let deposited = state
+        .members
+        .iter()
+        .find(|(account, _)| *account == proposer)
+        .map(|(_, m)| m.deposited)
+        .unwrap_or_else(Amount::zero);
+    if deposited < required.min_deposited {
+        return Err(DAOError::TierRequired);
+    }
+    Ok(())
+}
+
+/// Closes an open dispute with the given status, returning the votes to the voting power of the voters.
+fn close_dispute(
+    state: &mut DAOState,
+    dispute_id: u64,
+    status: DisputeStatus,
+) -> ReceiveResult<Dispute> {
+    let (_, dispute) = state
+        .disputes
+        .get_mut(dispute_id as usize)
+        .ok_or(DAOError::DisputeNotFound)?;
+
+    if dispute.status != DisputeStatus::Open {
+        return Err(DAOError::DisputeClosed.into());
+    }
+    dispute.status = status;
+    let dispute = dispute.clone();
+
+    state.return_votes(&dispute.contributers)?;
+
+    Ok(dispute)
+}
+
+/// Initialize the contract with empty proposals and members.
+#[init(contract = "DAO", parameter = "InitParams")]
+fn dao_init(ctx: &InitContext, _state_builder: &mut StateBuilder) -> InitResult<DAOState> {
+    let InitParams { name, config } = ctx.parameter_cursor().get()?;
+    config.validate()?;
+    let origin = ctx.init_origin();
+    Ok(DAOState {
+        name,
+        proposals: vec![],
+        members: vec![],
+        origin,
+        config,
+        config_revision: 0,
+        total_deposited: Amount::zero(),
+        disbursed: Amount::zero(),
+        implementors: vec![],
+        disputes: vec![],
+        blocked: vec![],
+        rounds: vec![],
+        signers: Signers {
+            signers: vec![],
+            required: 0,
+        },
+        reserve_released: Amount::zero(),
+        receipts: vec![],
+        operators: vec![],
+        fees: Amount::zero(),
+        dissolution: None,
+        owed: vec![],
+        registry: None,
+        guardians: vec![origin],
+    })
+}
+
+/// Checks whether the sender may propose with the attached bond: the exact bond has to be attached,
+/// the sender must not be blocked nor have overdue impact reports.
+fn check_proposer(ctx: &ReceiveContext, state: &DAOState, bond: Amount) -> Result<(), DAOError> {
+    ensure_not_dissolved(state)?;
+    if state.blocked.contains(&ctx.sender()) {
+        return Err(DAOError::Blocked);
+    }
+
+    // The exact bond has to be attached to the proposal.
+    if bond != state.config.proposal_bond {
+        return Err(DAOError::InvalidBond);
+    }
+
+    // Proposers have to report on their collected proposals before proposing again.
+    let now = ctx.metadata().slot_time();
+    if state
+        .proposals
+        .iter()
+        .any(|(_, p)| p.proposer == ctx.sender() && p.report_overdue(now))
+    {
+        return Err(DAOError::ReportsOverdue);
+    }
+
+    Ok(())
+}
+
+/// Adds a new active proposal of the sender, returning its id.
+fn push_proposal(
+    ctx: &ReceiveContext,
+    state: &mut DAOState,
+    bond: Amount,
+    description: String,
+    amount: Amount,
+    kind: ProposalKind,
+) -> Result<u64, DAOError> {
+    let deadline = ctx
+        .metadata()
+        .slot_time()
+        .checked_add(state.config.voting_period)
+        .ok_or(DAOError::Overflow)?;
+    let reveal_deadline = match state.config.reveal_period {
+        Some(period) => Some(deadline.checked_add(period).ok_or(DAOError::Overflow)?),
+        None => None,
+    };
+    let proposal_id = state.proposals.len() as u64;
+    state.proposals.push((
+        proposal_id,
+        Proposal {
+            proposer: ctx.sender(),
+            description,
+            amount,
+            votes: 0,
+            contributers: vec![],
+            status: Status::Active,
+            bond,
+            deadline,
+            donations: vec![],
+            report_deadline: None,
+            report: None,
+            revision: 0,
+            previous_description_hash: None,
+            confirmations: vec![],
+            kind,
+            euro: None,
+            reveal_deadline,
+            commitments: vec![],
+            config_revision: state.config_revision,
+        },
+    ));
+    Ok(proposal_id)
+}
+
+/// Function to create a proposal; can be invoked by anyone who attaches the proposal bond,
+/// has no overdue impact reports and is not blocked. Proposals above the gated amount
+/// can only be created by members of the required tier or higher.
+#[receive(
+    contract = "DAO",
+    name = "create_proposal",
+    parameter = "ProposalInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_create_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    bond: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalInput = ctx.parameter_cursor().get()?;
+    let description = input.clone().description;
+    let amount = input.clone().amount;
+    let state = host.state_mut();
+
+    if amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    check_proposer(ctx, state, bond)?;
+    check_gate(state, ctx.sender(), amount)?;
+
+    let proposal_id = push_proposal(
+        ctx,
+        state,
+        bond,
+        input.description,
+        input.amount,
+        ProposalKind::Funding,
+    )?;
+
+    logger.log(&DAOEvent::ProposalCreated {
+        proposal_id,
+        description,
+        amount,
+    })?;
+
+    Ok(())
+}
+
+/// Function to create a proposal denominated in EUR; can be invoked by anyone who may create proposals.
+/// The amount is converted to CCD at the exchange rate of the chain on approval and again on payout,
+/// which may deviate from the amount at approval by at most the slippage bound.
+#[receive(
+    contract = "DAO",
+    name = "create_euro_proposal",
+    parameter = "EuroProposalInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_create_euro_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    bond: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: EuroProposalInput = ctx.parameter_cursor().get()?;
+    let amount = input.amount.to_ccd(&host.exchange_rates())?;
+    let state = host.state_mut();
+
+    if input.amount.euro_cents == 0 || amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+    if input.amount.max_slippage_bps > MAX_BPS {
+        return Err(DAOError::InvalidConfig.into());
+    }
+
+    check_proposer(ctx, state, bond)?;
+    check_gate(state, ctx.sender(), amount)?;
+
+    let proposal_id = push_proposal(
+        ctx,
+        state,
+        bond,
+        input.description.clone(),
+        amount,
+        ProposalKind::Funding,
+    )?;
+    state.proposals[proposal_id as usize].1.euro = Some(input.amount);
+
+    logger.log(&DAOEvent::ProposalCreated {
+        proposal_id,
+        description: input.description,
+        amount,
+    })?;
+
+    Ok(())
+}
+
+/// Function to create a governance proposal, which is executed once it reaches the governance threshold;
+/// can be invoked by anyone who may create proposals. Votes on governance proposals are returned on execution.
+#[receive(
+    contract = "DAO",
+    name = "create_governance_proposal",
+    parameter = "GovernanceInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_create_governance_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    bond: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: GovernanceInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    check_proposer(ctx, state, bond)?;
+    input.action.validate()?;
+
+    let proposal_id = push_proposal(
+        ctx,
+        state,
+        bond,
+        input.description.clone(),
+        Amount::zero(),
+        ProposalKind::Governance(input.action),
+    )?;
+
+    logger.log(&DAOEvent::ProposalCreated {
+        proposal_id,
+        description: input.description,
+        amount: Amount::zero(),
+    })?;
+
+    Ok(())
+}
+
+/// Function to amend the description and amount of an open funding proposal; can only be invoked by the proposer.
+/// If the amount is increased, the votes are returned to the voters, who have to vote again.
+#[receive(
+    contract = "DAO",
+    name = "amend_proposal",
+    parameter = "AmendInput",
+    error = "DAOError",
+    mutable,
+    enable_logger,
+    crypto_primitives
+)]
+fn dao_amend_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    crypto_primitives: &CryptoPrimitives,
+) -> ReceiveResult<()> {
+    let input: AmendInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    if input.amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    check_gate(state, ctx.sender(), input.amount)?;
+
+    let (_, proposal) = state
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.proposer != ctx.sender() {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    ensure_open(ctx, proposal)?;
+    if proposal.kind != ProposalKind::Funding {
+        return Err(DAOError::NotFunding.into());
+    }
+    if proposal.euro.is_some() {
+        return Err(DAOError::EuroDenominated.into());
+    }
+
+    // Earmarked donations can't exceed the amount they are paid out with.
+    if input.amount < proposal.earmarked()? {
+        return Err(DAOError::BelowDonations.into());
+    }
+
+    let votes_reset = input.amount > proposal.amount;
+    let contributers = if votes_reset {
+        proposal.votes = 0;
+        core::mem::take(&mut proposal.contributers)
+    } else {
+        vec![]
+    };
+
+    proposal.revision = proposal.revision.checked_add(1).ok_or(DAOError::Overflow)?;
+    proposal.previous_description_hash =
+        Some(crypto_primitives.hash_sha2_256(proposal.description.as_bytes()));
+    proposal.description = input.description.clone();
+    proposal.amount = input.amount;
+
+    logger.log(&DAOEvent::Amended {
+        proposal_id: input.proposal_id,
+        revision: proposal.revision,
+        description: input.description,
+        amount: input.amount,
+        votes_reset,
+    })?;
+
+    // Returning the votes to the voting power of the contributers.
+    state.return_votes(&contributers)?;
+
+    // A decreased amount might already be covered by the funding.
+    try_approve(ctx, host, logger, input.proposal_id)
+}
+
+/// Casts votes of the sender on a proposal accepting them in its current phase, approving it
+/// once its threshold is reached.
+fn cast_vote(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    input: VoteInput,
+    ensure_phase: fn(&ReceiveContext, &Proposal) -> Result<(), DAOError>,
+) -> ReceiveResult<()> {
+    let rates = host.exchange_rates();
+    let state = host.state_mut();
+    let voter = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if input.votes == 0 {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    // Checking whether the sender has enough power to vote.
+    if state.members.is_empty()
+        || !state
+            .members
+            .iter()
+            .any(|m| m.0 == voter && m.1.power > 0 && m.1.power >= input.votes)
+    {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    let proposal_data = state
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    // Votes are only accepted while the proposal is active and in the right phase.
+    ensure_phase(ctx, &proposal_data.1)?;
+
+    // Votes beyond the threshold are not accepted and stay with the voter.
+    let threshold = match (&proposal_data.1.kind, &proposal_data.1.euro) {
+        (ProposalKind::Governance(_), _) => state.config.governance_threshold,
+        (ProposalKind::Funding, Some(euro)) => euro.to_ccd(&rates)?.micro_ccd(),
+        (ProposalKind::Funding, None) => proposal_data.1.amount.micro_ccd(),
+    };
+    let accepted = input
+        .votes
+        .min(threshold.saturating_sub(proposal_data.1.funding()?));
+    let returned = input.votes - accepted;
+
+    proposal_data.1.votes = proposal_data
+        .1
+        .votes
+        .checked_add(accepted)
+        .ok_or(DAOError::Overflow)?;
+    let mut exists = accepted == 0;
+
+    // Checking whether the sender has voted already.
+    for (v, votes) in proposal_data.1.contributers.iter_mut() {
+        if *v == voter {
+            // Incrementing the votes if already voted.
+            *votes = votes.checked_add(accepted).ok_or(DAOError::Overflow)?;
+            exists = true;
+            break;
+        }
+    }
+
+    if !exists {
+        proposal_data.1.contributers.push((voter, accepted));
+    }
+
+    proposal_data.1.check_votes()?;
+
+    logger.log(&DAOEvent::Voted {
+        proposal_id: input.proposal_id,
+        voter,
+        accepted,
+        returned,
+        total_votes: proposal_data.1.votes,
+    })?;
+
+    // Decrementing the voting power of the voter.
+    for (account, member) in state.members.iter_mut() {
+        if *account == voter {
+            member.power = member
+                .power
+                .checked_sub(accepted)
+                .ok_or(DAOError::Overflow)?;
+        }
+    }
+
+    try_approve(ctx, host, logger, input.proposal_id)
+}
+
+/// Function to vote on a proposal; can only be invoked by members.
+#[receive(
+    contract = "DAO",
+    name = "vote",
+    parameter = "VoteInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_vote(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: VoteInput = ctx.parameter_cursor().get()?;
+    cast_vote(ctx, host, logger, input, ensure_public)
+}
+
+/// Function to vote on several proposals at once; can only be invoked by members.
+/// Either all votes are cast or none.
+#[receive(
+    contract = "DAO",
+    name = "vote_batch",
+    parameter = "Vec<VoteInput>",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_vote_batch(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let inputs: Vec<VoteInput> = ctx.parameter_cursor().get()?;
+    for input in inputs {
+        cast_vote(ctx, host, logger, input, ensure_public)?;
+    }
+    Ok(())
+}
+
+/// Function to commit to votes on a commit-reveal proposal during its voting period; can only be invoked
+/// by members. A later commitment replaces the earlier one. Commitments that are not revealed count for nothing.
+#[receive(
+    contract = "DAO",
+    name = "commit_vote",
+    parameter = "CommitInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_commit_vote(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: CommitInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let voter = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if !state.members.iter().any(|(address, _)| *address == voter) {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    let (_, proposal) = state
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_open(ctx, proposal)?;
+    if proposal.reveal_deadline.is_none() {
+        return Err(DAOError::NotCommitReveal.into());
+    }
+
+    proposal.commitments.retain(|(v, _)| *v != voter);
+    proposal.commitments.push((voter, input.commitment));
+
+    logger.log(&DAOEvent::VoteCommitted {
+        proposal_id: input.proposal_id,
+        voter,
+    })?;
+
+    Ok(())
+}
+
+/// Function to reveal committed votes on a commit-reveal proposal during its reveal phase,
+/// casting them; can only be invoked by members with enough power left.
+#[receive(
+    contract = "DAO",
+    name = "reveal_vote",
+    parameter = "RevealInput",
+    error = "DAOError",
+    mutable,
+    enable_logger,
+    crypto_primitives
+)]
+fn dao_reveal_vote(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    crypto_primitives: &CryptoPrimitives,
+) -> ReceiveResult<()> {
+    let input: RevealInput = ctx.parameter_cursor().get()?;
+    let voter = ctx.sender();
+
+    let (_, proposal) = host
+        .state_mut()
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_revealing(ctx, proposal)?;
+
+    let commitment = vote_commitment(
+        crypto_primitives,
+        voter,
+        input.proposal_id,
+        input.votes,
+        &input.salt,
+    );
+    let index = proposal
+        .commitments
+        .iter()
+        .position(|c| *c == (voter, commitment))
+        .ok_or(DAOError::CommitmentMismatch)?;
+    proposal.commitments.remove(index);
+
+    let vote = VoteInput {
+        proposal_id: input.proposal_id,
+        votes: input.votes,
+    };
+    cast_vote(ctx, host, logger, vote, ensure_revealing)
+}
+
+/// Function to compute the commitment to votes for [`DAO.commit_vote`]. It should be invoked locally,
+/// as the parameter contains the secret salt.
+#[receive(
+    contract = "DAO",
+    name = "vote_commitment",
+    parameter = "CommitmentInput",
+    return_value = "HashSha2256",
+    error = "DAOError",
+    crypto_primitives
+)]
+fn dao_vote_commitment(
+    ctx: &ReceiveContext,
+    _host: &Host<DAOState>,
+    crypto_primitives: &CryptoPrimitives,
+) -> ReceiveResult<HashSha2256> {
+    let input: CommitmentInput = ctx.parameter_cursor().get()?;
+
+    Ok(vote_commitment(
+        crypto_primitives,
+        input.voter,
+        input.proposal_id,
+        input.votes,
+        &input.salt,
+    ))
+}
+
+/// Function to tally a commit-reveal proposal after its reveal phase; can be invoked by anyone.
+/// The proposal is approved if the revealed votes reach its threshold and the treasury covers the
+/// payout, and expires otherwise.
+#[receive(
+    contract = "DAO",
+    name = "tally",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_tally(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_active(proposal)?;
+    let reveal_deadline = proposal.reveal_deadline.ok_or(DAOError::NotCommitReveal)?;
+    if ctx.metadata().slot_time() < reveal_deadline {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    // Proposals the treasury can't pay out once the votes are final expire, like those that fall
+    // short of their threshold.
+    match approve(ctx, host, logger, input.proposal_id) {
+        Err(reject) if reject == DAOError::InsufficientBalance.into() => {}
+        result => result?,
+    }
+
+    if host.state().proposals[input.proposal_id as usize].1.status == Status::Active {
+        expire_proposal(host, logger, input.proposal_id)?;
+    }
+
+    Ok(())
+}
+
+/// Renounces votes of the sender on a proposal.
+fn renounce_votes(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    input: VoteInput,
+) -> ReceiveResult<()> {
+    let state = host.state_mut();
+    let voter = ctx.sender();
+
+    if input.votes == 0 {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    // Checking whether the sender is a member.
+    if state.members.is_empty() || !state.members.iter().any(|m| m.0 == voter) {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    let proposal_data = state
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    // Can't renounce if the proposal is approved or closed.
+    ensure_open(ctx, &proposal_data.1)?;
+
+    // Locating the contribution of the sender.
+    let index = proposal_data
+        .1
+        .contributers
+        .iter()
+        .position(|(v, _)| *v == voter)
+        .ok_or(DAOError::NotAVoter)?;
+    let votes = &mut proposal_data.1.contributers[index].1;
+
+    // Renounce a particular amount of votes, or all if it exceeds the contribution.
+    let renounce = input.votes.min(*votes);
+    *votes -= renounce;
+    proposal_data.1.votes = proposal_data
+        .1
+        .votes
+        .checked_sub(renounce)
+        .ok_or(DAOError::Overflow)?;
+
+    // Dropping the contribution once nothing is left.
+    if proposal_data.1.contributers[index].1 == 0 {
+        proposal_data.1.contributers.remove(index);
+    }
+
+    proposal_data.1.check_votes()?;
+
+    logger.log(&DAOEvent::Renounced {
+        proposal_id: input.proposal_id,
+        voter,
+        total_votes: proposal_data.1.votes,
+    })?;
+
+    // Incrementing the voting power of the voter.
+    for (account, member) in state.members.iter_mut() {
+        if *account == voter {
+            member.power = member
+                .power
+                .checked_add(renounce)
+                .ok_or(DAOError::Overflow)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Function to renounce votes on a proposal; can only be invoked by contributers (voters).
+#[receive(
+    contract = "DAO",
+    name = "renounce",
+    parameter = "VoteInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_renounce(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: VoteInput = ctx.parameter_cursor().get()?;
+    renounce_votes(ctx, host, logger, input)
+}
+
+/// Function to renounce votes on several proposals at once; can only be invoked by contributers (voters).
+/// Either all votes are renounced or none.
+#[receive(
+    contract = "DAO",
+    name = "renounce_batch",
+    parameter = "Vec<VoteInput>",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_renounce_batch(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let inputs: Vec<VoteInput> = ctx.parameter_cursor().get()?;
+    for input in inputs {
+        renounce_votes(ctx, host, logger, input)?;
+    }
+    Ok(())
+}
+
+/// Function to veto an active proposal, forfeiting its bond to the DAO; can only be invoked by a guardian.
+#[receive(
+    contract = "DAO",
+    name = "veto",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_veto(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+
+    ensure_guardian(ctx, host.state())?;
+
+    logger.log(&DAOEvent::Vetoed {
+        proposal_id: input.proposal_id,
+    })?;
+
+    let proposal = close_proposal(host, logger, input.proposal_id, Status::Vetoed)?;
+    settle_bond(host, logger, input.proposal_id, &proposal, false)
+}
+
+/// Function to close an active proposal once its voting period has passed; can be invoked by anyone.
+/// The bond is refunded if the proposal attained the minimum support, otherwise it is forfeited.
+/// Commit-reveal proposals are tallied instead.
+#[receive(
+    contract = "DAO",
+    name = "expire",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_expire(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_active(proposal)?;
+    // Commit-reveal proposals have to be tallied instead.
+    if proposal.reveal_deadline.is_some() {
+        return Err(DAOError::CommitRevealRequired.into());
+    }
+    if ctx.metadata().slot_time() < proposal.deadline {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    expire_proposal(host, logger, input.proposal_id)
+}
+
+/// Expires an active proposal. The bond is refunded if the proposal had the minimum support.
+fn expire_proposal(
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+    proposal_id: u64,
+) -> ReceiveResult<()> {
+    let min_support = host.state().config.min_support;
+
+    logger.log(&DAOEvent::Expired { proposal_id })?;
+
+    let proposal = close_proposal(host, logger, proposal_id, Status::Expired)?;
+    let funding = proposal.funding()?;
+    let supported = funding > 0 && funding >= min_support;
+    settle_bond(host, logger, proposal_id, &proposal, supported)
+}
+
+/// Function to cancel an active proposal; can only be invoked by the proposer.
+/// The bond is refunded if the proposal attained the minimum support, otherwise it is forfeited.
+#[receive(
+    contract = "DAO",
+    name = "cancel_proposal",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_cancel_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+    let state = host.state();
+    let min_support = state.config.min_support;
+
+    let (_, proposal) = state
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.proposer != ctx.sender() {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    logger.log(&DAOEvent::Cancelled {
+        proposal_id: input.proposal_id,
+    })?;
+
+    let proposal = close_proposal(host, logger, input.proposal_id, Status::Cancelled)?;
+    let funding = proposal.funding()?;
+    let supported = funding > 0 && funding >= min_support;
+    settle_bond(host, logger, input.proposal_id, &proposal, supported)
+}
+
+/// Function to donate CCD directly to a proposal, allowed to anyone. Earmarked donations count towards
+/// the funding of the proposal and are paid out with it; donations beyond its threshold are returned.
+#[receive(
+    contract = "DAO",
+    name = "donate_to_proposal",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_donate_to_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    amount: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+    let donor = ctx.sender();
+    let rates = host.exchange_rates();
+
+    if amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    let proposal_data = host
+        .state_mut()
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    ensure_open(ctx, &proposal_data.1)?;
+    if proposal_data.1.kind != ProposalKind::Funding {
+        return Err(DAOError::NotFunding.into());
+    }
+
+    // Only accepting what the proposal still needs, at the current exchange rate if denominated
+    // in EUR.
+    let target = match &proposal_data.1.euro {
+        Some(euro) => euro.to_ccd(&rates)?,
+        None => proposal_data.1.amount,
+    };
+    let needed = target
+        .micro_ccd()
+        .saturating_sub(proposal_data.1.funding()?);
+    let accepted = Amount::from_micro_ccd(amount.micro_ccd().min(needed));
+    let surplus = amount.checked_sub(accepted).ok_or(DAOError::Overflow)?;
+
+    let mut exists = false;
+    for (d, donated) in proposal_data.1.donations.iter_mut() {
+        if *d == donor {
+            *donated = checked_add_amounts(*donated, accepted)?;
+            exists = true;
+            break;
+        }
+    }
+
+    if !exists {
+        proposal_data.1.donations.push((donor, accepted));
+    }
+
+    logger.log(&DAOEvent::Donated {
+        proposal_id: input.proposal_id,
+        donor,
+        amount: accepted,
+    })?;
+
+    if surplus > Amount::zero() {
+        pay_out(host, &donor, surplus)?;
+    }
+
+    try_approve(ctx, host, logger, input.proposal_id)
+}
+
+/// Function to submit the impact report of a collected proposal; can only be invoked by the proposer.
+#[receive(
+    contract = "DAO",
+    name = "submit_report",
+    parameter = "ReportInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_submit_report(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ReportInput = ctx.parameter_cursor().get()?;
+
+    let (_, proposal) = host
+        .state_mut()
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.proposer != ctx.sender() {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    match proposal.status {
+        Status::Collected => {}
+        Status::Reported => return Err(DAOError::AlreadyReported.into()),
+        _ => return Err(DAOError::NotCollected.into()),
+    }
+
+    proposal.status = Status::Reported;
+    proposal.report = Some(input.report.clone());
+
+    logger.log(&DAOEvent::Reported {
+        proposal_id: input.proposal_id,
+        report: input.report,
+    })?;
+
+    Ok(())
+}
+
+/// Function to flag a collected proposal as fraudulent, opening a dispute; can only be invoked by members.
+/// Proposals can be disputed only once, unless the previous dispute was rejected.
+#[receive(
+    contract = "DAO",
+    name = "flag_proposal",
+    parameter = "FlagInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_flag_proposal(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: FlagInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let flagger = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if !state.members.iter().any(|(account, _)| *account == flagger) {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    let (_, proposal) = state
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    // Only proposals which have been paid out can be disputed.
+    if !matches!(proposal.status, Status::Collected | Status::Reported) {
+        return Err(DAOError::NotCollected.into());
+    }
+
+    if state
+        .disputes
+        .iter()
+        .any(|(_, d)| d.proposal_id == input.proposal_id && d.status != DisputeStatus::Rejected)
+    {
+        return Err(DAOError::AlreadyDisputed.into());
+    }
+
+    let deadline = ctx
+        .metadata()
+        .slot_time()
+        .checked_add(state.config.voting_period)
+        .ok_or(DAOError::Overflow)?;
+    let dispute_id = state.disputes.len() as u64;
+    state.disputes.push((
+        dispute_id,
+        Dispute {
+            proposal_id: input.proposal_id,
+            flagger,
+            reason: input.reason,
+            votes: 0,
+            contributers: vec![],
+            status: DisputeStatus::Open,
+            deadline,
+        },
+    ));
+
+    logger.log(&DAOEvent::Flagged {
+        dispute_id,
+        proposal_id: input.proposal_id,
+        flagger,
+    })?;
+
+    Ok(())
+}
+
+/// Function to vote in favour of a dispute; can only be invoked by members. Once the dispute reaches
+/// the quorum, it is upheld and the proposer of the disputed proposal is blocked from creating proposals.
+#[receive(
+    contract = "DAO",
+    name = "vote_dispute",
+    parameter = "DisputeVoteInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_vote_dispute(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: DisputeVoteInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let voter = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if input.votes == 0 {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    // Decrementing the voting power of the voter.
+    let (_, member) = state
+        .members
+        .iter_mut()
+        .find(|(account, _)| *account == voter)
+        .ok_or(DAOError::Unauthorized)?;
+    member.power = member
+        .power
+        .checked_sub(input.votes)
+        .ok_or(DAOError::Unauthorized)?;
+
+    let (_, dispute) = state
+        .disputes
+        .get_mut(input.dispute_id as usize)
+        .ok_or(DAOError::DisputeNotFound)?;
+
+    if dispute.status != DisputeStatus::Open {
+        return Err(DAOError::DisputeClosed.into());
+    }
+    if ctx.metadata().slot_time() >= dispute.deadline {
+        return Err(DAOError::VotingClosed.into());
+    }
+
+    dispute.votes = dispute
+        .votes
+        .checked_add(input.votes)
+        .ok_or(DAOError::Overflow)?;
+    match dispute.contributers.iter_mut().find(|(v, _)| *v == voter) {
+        Some((_, votes)) => *votes = votes.checked_add(input.votes).ok_or(DAOError::Overflow)?,
+        None => dispute.contributers.push((voter, input.votes)),
+    }
+
+    logger.log(&DAOEvent::DisputeVoted {
+        dispute_id: input.dispute_id,
+        voter,
+        total_votes: dispute.votes,
+    })?;
+
+    if dispute.votes < state.config.dispute_quorum {
+        return Ok(());
+    }
+
+    // Upholding the dispute and blocking the proposer, who is also the beneficiary of the payout.
+    let dispute = close_dispute(state, input.dispute_id, DisputeStatus::Upheld)?;
+    let proposer = state.proposals[dispute.proposal_id as usize].1.proposer;
+    if !state.blocked.contains(&proposer) {
+        state.blocked.push(proposer);
+    }
+
+    logger.log(&DAOEvent::DisputeUpheld {
+        dispute_id: input.dispute_id,
+        blocked: proposer,
+    })?;
+
+    Ok(())
+}
+
+/// Function to reject a dispute which has not reached the quorum within the voting period;
+/// can be invoked by anyone. The voters get their power back.
+#[receive(
+    contract = "DAO",
+    name = "close_dispute",
+    parameter = "DisputeIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_close_dispute(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: DisputeIdInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    let (_, dispute) = state
+        .disputes
+        .get(input.dispute_id as usize)
+        .ok_or(DAOError::DisputeNotFound)?;
+
+    if ctx.metadata().slot_time() < dispute.deadline {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    close_dispute(state, input.dispute_id, DisputeStatus::Rejected)?;
+
+    logger.log(&DAOEvent::DisputeRejected {
+        dispute_id: input.dispute_id,
+    })?;
+
+    Ok(())
+}
+
+/// Function to open a quadratic funding round, depositing the attached amount as its matching pool;
+/// allowed to anyone.
+#[receive(
+    contract = "DAO",
+    name = "create_round",
+    parameter = "RoundInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_create_round(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    pool: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RoundInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let sponsor = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if pool == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    let deadline = ctx
+        .metadata()
+        .slot_time()
+        .checked_add(input.duration)
+        .ok_or(DAOError::Overflow)?;
+    let round_id = state.rounds.len() as u64;
+    state.rounds.push((
+        round_id,
+        Round {
+            sponsor,
+            pool,
+            deadline,
+            contributions: vec![],
+            status: RoundStatus::Open,
+            payouts: vec![],
+        },
+    ));
+
+    logger.log(&DAOEvent::RoundCreated {
+        round_id,
+        sponsor,
+        pool,
+    })?;
+
+    Ok(())
+}
+
+/// Function to contribute CCD to a proposal in an open funding round, allowed to anyone.
+/// Only active or approved proposals accept contributions.
+#[receive(
+    contract = "DAO",
+    name = "contribute",
+    parameter = "RoundProposalInput",
+    error = "DAOError",
+    mutable,
+    payable,
+    enable_logger
+)]
+fn dao_contribute(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    amount: Amount,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RoundProposalInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let contributor = ctx.sender();
+
+    ensure_not_dissolved(state)?;
+    if amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    let (_, proposal) = state
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+    if proposal.kind != ProposalKind::Funding {
+        return Err(DAOError::NotFunding.into());
+    }
+    if !matches!(proposal.status, Status::Active | Status::Approved) {
+        return Err(DAOError::ProposalClosed.into());
+    }
+
+    let (_, round) = state
+        .rounds
+        .get_mut(input.round_id as usize)
+        .ok_or(DAOError::RoundNotFound)?;
+    if round.status != RoundStatus::Open {
+        return Err(DAOError::RoundClosed.into());
+    }
+    if ctx.metadata().slot_time() >= round.deadline {
+        return Err(DAOError::VotingClosed.into());
+    }
+
+    match round
+        .contributions
+        .iter_mut()
+        .find(|c| c.proposal_id == input.proposal_id && c.contributor == contributor)
+    {
+        Some(c) => c.amount = checked_add_amounts(c.amount, amount)?,
+        None => round.contributions.push(Contribution {
+            proposal_id: input.proposal_id,
+            contributor,
+            amount,
+        }),
+    }
+
+    logger.log(&DAOEvent::Contributed {
+        round_id: input.round_id,
+        proposal_id: input.proposal_id,
+        contributor,
+        amount,
+    })?;
+
+    Ok(())
+}
+
+/// Function to close a funding round once its duration has passed, computing the quadratic funding
+/// matches; can be invoked by anyone. Contributions to vetoed, expired or cancelled proposals are
+/// refunded and the part of the pool left unmatched is returned to the sponsor.
+#[receive(
+    contract = "DAO",
+    name = "close_round",
+    parameter = "RoundIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_close_round(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RoundIdInput = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    let (_, round) = state
+        .rounds
+        .get(input.round_id as usize)
+        .ok_or(DAOError::RoundNotFound)?;
+    if round.status != RoundStatus::Open {
+        return Err(DAOError::RoundClosed.into());
+    }
+    if ctx.metadata().slot_time() < round.deadline {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    // Splitting the contributed proposals into those eligible for matching and those closed unfunded.
+    let mut eligible: Vec<u64> = vec![];
+    let mut refunds: Vec<(Address, Amount)> = vec![];
+    for c in round.contributions.iter() {
+        if matches!(
+            state.proposals[c.proposal_id as usize].1.status,
+            Status::Vetoed | Status::Expired | Status::Cancelled
+        ) {
+            refunds.push((c.contributor, c.amount));
+        } else if !eligible.contains(&c.proposal_id) {
+            eligible.push(c.proposal_id);
+        }
+    }
+
+    let (payouts, unmatched) = round.compute_matches(&eligible)?;
+    let sponsor = round.sponsor;
+
+    let (_, round) = &mut host.state_mut().rounds[input.round_id as usize];
+    round.status = RoundStatus::Closed;
+    round.payouts = payouts.clone();
+
+    for payout in payouts {
+        logger.log(&DAOEvent::Matched {
+            round_id: input.round_id,
+            proposal_id: payout.proposal_id,
+            contributed: payout.contributed,
+            matched: payout.matched,
+        })?;
+    }
+
+    for (contributor, amount) in refunds {
+        pay_refund(host, logger, &contributor, amount)?;
+    }
+
+    if unmatched > Amount::zero() {
+        pay_refund(host, logger, &sponsor, unmatched)?;
+    }
+
+    logger.log(&DAOEvent::RoundClosed {
+        round_id: input.round_id,
+        returned: unmatched,
+    })?;
+
+    Ok(())
+}
+
+/// Function to claim the contributions and match of an approved proposal in a closed funding round;
+/// can only be invoked by the proposer, unless blocked. Payouts of blocked proposers and of proposals
+/// closed without funding are released through [`DAO.release_match`].
+#[receive(
+    contract = "DAO",
+    name = "claim_match",
+    parameter = "RoundProposalInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_claim_match(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RoundProposalInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let proposer = ctx.sender();
+
+    let (_, proposal) = state
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+    if proposal.proposer != proposer {
+        return Err(DAOError::Unauthorized.into());
+    }
+    if state.blocked.contains(&proposer) {
+        return Err(DAOError::Blocked.into());
+    }
+    // Active proposals might still be vetoed or expire, so the payout waits for their approval.
+    if !matches!(
+        proposal.status,
+        Status::Approved | Status::Collected | Status::Reported
+    ) {
+        return Err(DAOError::NotApproved.into());
+    }
+
+    let (_, round) = state
+        .rounds
+        .get_mut(input.round_id as usize)
+        .ok_or(DAOError::RoundNotFound)?;
+    if round.status != RoundStatus::Closed {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    let payout = round
+        .payouts
+        .iter_mut()
+        .find(|p| p.proposal_id == input.proposal_id)
+        .ok_or(DAOError::ProposalNotFound)?;
+    if payout.claimed {
+        return Err(DAOError::AlreadyClaimed.into());
+    }
+    payout.claimed = true;
+
+    let amount = checked_add_amounts(payout.contributed, payout.matched)?;
+    state.disbursed = checked_add_amounts(state.disbursed, amount)?;
+
+    pay_out(host, &proposer, amount)?;
+
+    logger.log(&DAOEvent::MatchClaimed {
+        round_id: input.round_id,
+        proposal_id: input.proposal_id,
+        proposer,
+        amount,
+    })?;
+
+    Ok(())
+}
+
+/// Function to release the payout of a proposal in a closed funding round, once its proposer is
+/// blocked or it was vetoed, expired or cancelled; can be invoked by anyone. The contributions are
+/// refunded to the contributors and the match is returned to the sponsor.
+#[receive(
+    contract = "DAO",
+    name = "release_match",
+    parameter = "RoundProposalInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_release_match(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RoundProposalInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    let (_, proposal) = state
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+    let unfunded = matches!(
+        proposal.status,
+        Status::Vetoed | Status::Expired | Status::Cancelled
+    );
+    if !unfunded && !state.blocked.contains(&proposal.proposer) {
+        return Err(DAOError::Unauthorized.into());
+    }
+
+    let (_, round) = state
+        .rounds
+        .get_mut(input.round_id as usize)
+        .ok_or(DAOError::RoundNotFound)?;
+    if round.status != RoundStatus::Closed {
+        return Err(DAOError::VotingOpen.into());
+    }
+
+    let payout = round
+        .payouts
+        .iter_mut()
+        .find(|p| p.proposal_id == input.proposal_id)
+        .ok_or(DAOError::ProposalNotFound)?;
+    if payout.claimed {
+        return Err(DAOError::AlreadyClaimed.into());
+    }
+    payout.claimed = true;
+    let payout = payout.clone();
+
+    let sponsor = round.sponsor;
+    let refunds: Vec<(Address, Amount)> = round
+        .contributions
+        .iter()
+        .filter(|c| c.proposal_id == input.proposal_id)
+        .map(|c| (c.contributor, c.amount))
+        .collect();
+
+    for (contributor, amount) in refunds {
+        pay_refund(host, logger, &contributor, amount)?;
+    }
+
+    if payout.matched > Amount::zero() {
+        pay_refund(host, logger, &sponsor, payout.matched)?;
+    }
+
+    logger.log(&DAOEvent::MatchReleased {
+        round_id: input.round_id,
+        proposal_id: input.proposal_id,
+        refunded: payout.contributed,
+        returned: payout.matched,
+    })?;
+
+    Ok(())
+}
+
+/// Function to fetch all funding rounds.
+#[receive(
+    contract = "DAO",
+    name = "all_rounds",
+    return_value = "Vec<(u64, Round)>",
+    error = "DAOError"
+)]
+fn dao_all_rounds(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<(u64, Round)>> {
+    Ok(host.state().rounds.clone())
+}
+
+/// Function to set the signers confirming large payouts; can only be invoked by a guardian.
+/// Confirmations of signers who are removed no longer count.
+#[receive(
+    contract = "DAO",
+    name = "set_signers",
+    parameter = "Signers",
+    error = "DAOError",
+    mutable
+)]
+fn dao_set_signers(ctx: &ReceiveContext, host: &mut Host<DAOState>) -> ReceiveResult<()> {
+    let input: Signers = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    ensure_guardian(ctx, state)?;
+
+    input.validate()?;
+    state.signers = input;
+    Ok(())
+}
+
+/// Function to confirm the payout of an approved proposal; can only be invoked by the signers.
+#[receive(
+    contract = "DAO",
+    name = "confirm_payout",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_confirm_payout(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Signers are accounts.
+    let signer = match ctx.sender() {
+        Address::Account(account) if state.signers.signers.contains(&account) => account,
+        _ => return Err(DAOError::Unauthorized.into()),
+    };
+
+    let (_, proposal) = state
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.kind != ProposalKind::Funding {
+        return Err(DAOError::NotFunding.into());
+    }
+    if proposal.status != Status::Approved {
+        return Err(DAOError::NotApproved.into());
+    }
+    if proposal.confirmations.contains(&signer) {
+        return Err(DAOError::AlreadyConfirmed.into());
+    }
+
+    proposal.confirmations.push(signer);
+
+    logger.log(&DAOEvent::PayoutConfirmed {
+        proposal_id: input.proposal_id,
+        signer,
+        confirmations: proposal.confirmations.len() as u32,
+    })?;
+
+    Ok(())
+}
+
+/// Function to revoke the confirmation of a payout which is yet to be collected; can only be invoked
+/// by the signer who has confirmed it.
+#[receive(
+    contract = "DAO",
+    name = "revoke_confirmation",
+    parameter = "ProposalIdInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_revoke_confirmation(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+    let signer = match ctx.sender() {
+        Address::Account(account) => account,
+        Address::Contract(_) => return Err(DAOError::NotConfirmed.into()),
+    };
+
+    let (_, proposal) = host
+        .state_mut()
+        .proposals
+        .get_mut(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    if proposal.status != Status::Approved {
+        return Err(DAOError::NotApproved.into());
+    }
+
+    let index = proposal
+        .confirmations
+        .iter()
+        .position(|s| *s == signer)
+        .ok_or(DAOError::NotConfirmed)?;
+    proposal.confirmations.remove(index);
+
+    logger.log(&DAOEvent::ConfirmationRevoked {
+        proposal_id: input.proposal_id,
+        signer,
+        confirmations: proposal.confirmations.len() as u32,
+    })?;
+
+    Ok(())
+}
+
+/// Function to fetch the signers confirming large payouts.
+#[receive(
+    contract = "DAO",
+    name = "signers",
+    return_value = "Signers",
+    error = "DAOError"
+)]
+fn dao_signers(_ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<Signers> {
+    Ok(host.state().signers.clone())
+}
+
+/// Function to fetch the guardians of the DAO.
+#[receive(
+    contract = "DAO",
+    name = "guardians",
+    return_value = "Vec<AccountAddress>",
+    error = "DAOError"
+)]
+fn dao_guardians(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<AccountAddress>> {
+    Ok(host.state().guardians.clone())
+}
+
+/// Function to fetch all proposals.
+#[receive(
+    contract = "DAO",
+    name = "all_proposals",
+    return_value = "Vec<(u64, Proposal)>",
+    error = "DAOError"
+)]
+fn dao_all_proposals(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<(u64, Proposal)>> {
+    Ok(host.state().proposals.clone())
+}
+
+/// Function to fetch all members.
+#[receive(
+    contract = "DAO",
+    name = "all_members",
+    return_value = "Vec<(Address,u64)>",
+    error = "DAOError"
+)]
+fn dao_all_members(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<(Address, u64)>> {
+    Ok(host
+        .state()
+        .members
+        .iter()
+        .map(|(account, member)| (*account, member.power))
+        .collect())
+}
+
+/// Function to fetch a single proposal.
+#[receive(
+    contract = "DAO",
+    name = "get_proposal",
+    parameter = "ProposalIdInput",
+    return_value = "Proposal",
+    error = "DAOError"
+)]
+fn dao_get_proposal(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<Proposal> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    Ok(proposal.clone())
+}
+
+/// Function to fetch the amount of a proposal in EUR and its CCD equivalent at the current exchange rate.
+#[receive(
+    contract = "DAO",
+    name = "get_proposal_value",
+    parameter = "ProposalIdInput",
+    return_value = "ProposalValue",
+    error = "DAOError"
+)]
+fn dao_get_proposal_value(
+    ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<ProposalValue> {
+    let input: ProposalIdInput = ctx.parameter_cursor().get()?;
+    let rates = host.exchange_rates();
+
+    let (_, proposal) = host
+        .state()
+        .proposals
+        .get(input.proposal_id as usize)
+        .ok_or(DAOError::ProposalNotFound)?;
+
+    let (euro_cents, current) = match &proposal.euro {
+        Some(euro) => (euro.euro_cents, euro.to_ccd(&rates)?),
+        None => (to_euro_cents(proposal.amount, &rates)?, proposal.amount),
+    };
+
+    Ok(ProposalValue {
+        amount: proposal.amount,
+        euro_cents,
+        current,
+    })
+}
+
+/// Function to fetch the membership of a particular address.
+#[receive(
+    contract = "DAO",
+    name = "get_member",
+    parameter = "AddressInput",
+    return_value = "MemberView",
+    error = "DAOError"
+)]
+fn dao_get_member(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<MemberView> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    let (_, member) = state
+        .members
+        .iter()
+        .find(|(account, _)| *account == input.address)
+        .ok_or(DAOError::NotAMember)?;
+
+    // Summing the votes of the member on active proposals.
+    let active_votes = checked_sum(
+        state
+            .proposals
+            .iter()
+            .filter(|(_, p)| p.status == Status::Active)
+            .flat_map(|(_, p)| p.contributers.iter())
+            .filter(|(voter, _)| *voter == input.address)
+            .map(|(_, votes)| *votes),
+    )?;
+
+    Ok(MemberView {
+        power: member.power,
+        deposited: member.deposited,
+        active_votes,
+        tier: state
+            .config
+            .tier_of(member.deposited)
+            .map(|t| t.name.clone()),
+    })
+}
+
+/// Function to fetch every proposal a particular address has voted on, with its votes.
+#[receive(
+    contract = "DAO",
+    name = "votes_of",
+    parameter = "AddressInput",
+    return_value = "Vec<(u64, u64)>",
+    error = "DAOError"
+)]
+fn dao_votes_of(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<Vec<(u64, u64)>> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+
+    Ok(host
+        .state()
+        .proposals
+        .iter()
+        .filter_map(|(id, p)| {
+            p.contributers
+                .iter()
+                .find(|(voter, _)| *voter == input.address)
+                .map(|(_, votes)| (*id, *votes))
+        })
+        .collect())
+}
+
+/// Function to fetch all disputes.
+#[receive(
+    contract = "DAO",
+    name = "all_disputes",
+    return_value = "Vec<(u64, Dispute)>",
+    error = "DAOError"
+)]
+fn dao_all_disputes(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<(u64, Dispute)>> {
+    Ok(host.state().disputes.clone())
+}
+
+/// Function to check whether a particular address is blocked from creating proposals.
+#[receive(
+    contract = "DAO",
+    name = "is_blocked",
+    parameter = "AddressInput",
+    return_value = "bool",
+    error = "DAOError"
+)]
+fn dao_is_blocked(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<bool> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+    Ok(host.state().blocked.contains(&input.address))
+}
+
+/// Function to fetch the track record of a particular proposer.
+#[receive(
+    contract = "DAO",
+    name = "track_record",
+    parameter = "AddressInput",
+    return_value = "TrackRecord",
+    error = "DAOError"
+)]
+fn dao_track_record(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<TrackRecord> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+    let now = ctx.metadata().slot_time();
+    let mut record = TrackRecord {
+        proposed: 0,
+        collected: 0,
+        reported: 0,
+        overdue: 0,
+    };
+
+    for (_, p) in host.state().proposals.iter() {
+        if p.proposer != input.address {
+            continue;
+        }
+        record.proposed += 1;
+        if matches!(p.status, Status::Collected | Status::Reported) {
+            record.collected += 1;
+        }
+        if p.status == Status::Reported {
+            record.reported += 1;
+        }
+        if p.report_overdue(now) {
+            record.overdue += 1;
+        }
+    }
+
+    Ok(record)
+}
+
+/// Function to query the standards supported by the DAO, as specified by CIS-0.
+/// Standards which are not implemented by the DAO itself may be supported by implementor contracts.
+#[receive(
+    contract = "DAO",
+    name = "supports",
+    parameter = "SupportsQueryParams",
+    return_value = "SupportsQueryResponse",
+    error = "DAOError"
+)]
+fn dao_supports(
+    ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<SupportsQueryResponse> {
+    let params: SupportsQueryParams = ctx.parameter_cursor().get()?;
+    let mut response = Vec::with_capacity(params.queries.len());
+
+    for std_id in params.queries {
+        if SUPPORTS_STANDARDS.contains(&std_id.as_standard_identifier()) {
+            response.push(SupportResult::Support);
+        } else {
+            let implementors = host
+                .state()
+                .implementors
+                .iter()
+                .find(|(id, _)| *id == std_id);
+            response.push(match implementors {
+                Some((_, addresses)) => SupportResult::SupportBy(addresses.clone()),
+                None => SupportResult::NoSupport,
+            });
+        }
+    }
+
+    Ok(SupportsQueryResponse::from(response))
+}
+
+/// Function to map a standard to the contracts implementing it for the DAO; can only be invoked by a guardian.
+#[receive(
+    contract = "DAO",
+    name = "set_implementors",
+    parameter = "SetImplementorsInput",
+    error = "DAOError",
+    mutable
+)]
+fn dao_set_implementors(ctx: &ReceiveContext, host: &mut Host<DAOState>) -> ReceiveResult<()> {
+    let input: SetImplementorsInput = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+
+    ensure_guardian(ctx, state)?;
+
+    state.implementors.retain(|(id, _)| *id != input.id);
+    if !input.implementors.is_empty() {
+        state.implementors.push((input.id, input.implementors));
+    }
+
+    Ok(())
+}
+
+/// Function to link the DAO to the registry registering it; can only be invoked by a contract
+/// on behalf of the origin.
+#[receive(contract = "DAO", name = "set_registry", error = "DAOError", mutable)]
+fn dao_set_registry(ctx: &ReceiveContext, host: &mut Host<DAOState>) -> ReceiveResult<()> {
+    let state = host.state_mut();
+
+    let registry = match ctx.sender() {
+        Address::Contract(registry) if ctx.invoker() == state.origin => registry,
+        _ => return Err(DAOError::Unauthorized.into()),
+    };
+    state.registry = Some(registry);
+
+    Ok(())
 }
 
-/// Initialize the contract with empty proposals and members.
-#[init(contract = "DAO")]
-fn dao_init(ctx: &InitContext, _state_builder: &mut StateBuilder) -> InitResult<DAOState> {
-    let origin = ctx.init_origin();
-    Ok(DAOState {
-        proposals: vec![],
-        members: vec![],
-        origin,
+/// Function to fetch the name, parameters and origin of the DAO.
+#[receive(
+    contract = "DAO",
+    name = "info",
+    return_value = "DAOInfo",
+    error = "DAOError"
+)]
+fn dao_info(_ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<DAOInfo> {
+    let state = host.state();
+
+    Ok(DAOInfo {
+        name: state.name.clone(),
+        config: state.config.clone(),
+        origin: state.origin,
     })
 }
 
-/// Function to create a proposal; can be invoked by anyone.
+/// Function to fetch the accounting of the treasury.
 #[receive(
     contract = "DAO",
-    name = "create_proposal",
-    parameter = "ProposalInput",
+    name = "treasury",
+    return_value = "TreasuryView",
+    error = "DAOError"
+)]
+fn dao_treasury(_ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<TreasuryView> {
+    let state = host.state();
+    let balance = host.self_balance();
+
+    Ok(TreasuryView {
+        balance,
+        total_deposited: state.total_deposited,
+        outstanding_power: state.outstanding_power()?,
+        committed: state.committed()?,
+        held: state.held()?,
+        in_rounds: state.in_rounds()?,
+        disbursed: state.disbursed,
+        reserve: state.reserve()?,
+        fees: state.fees,
+        owed: state.owed_total()?,
+        free_balance: state.free_balance(balance)?,
+    })
+}
+
+/// Function to fetch voting power of a particular address.
+#[receive(
+    contract = "DAO",
+    name = "get_power",
+    parameter = "AddressInput",
+    return_value = "u64",
+    error = "DAOError"
+)]
+fn dao_get_power(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<u64> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    for (address, member) in state.members.iter() {
+        if *address == input.address {
+            return Ok(member.power);
+        }
+    }
+
+    Ok(0)
+}
+
+/// Function to insert some CCD into DAO, allowed to anyone. This will grant membership in DAO,
+/// provided the first deposit meets the minimum deposit. The operations fee is deducted from every
+/// deposit, and every deposit mints a donation receipt to the donor.
+#[receive(
+    contract = "DAO",
+    name = "insert",
     error = "DAOError",
     mutable,
+    payable,
     enable_logger
 )]
-fn dao_create_proposal(
+fn dao_insert(
     ctx: &ReceiveContext,
     host: &mut Host<DAOState>,
+    amount: Amount,
     logger: &mut Logger,
 ) -> ReceiveResult<()> {
-    let input: ProposalInput = ctx.parameter_cursor().get()?;
-    let description = input.clone().description;
-    let amount = input.clone().amount;
+    let sender = ctx.sender();
     let state = host.state_mut();
-    let proposal_id = state.proposals.len() as u64;
-    state.proposals.push((
-        proposal_id,
-        Proposal {
-            proposer: ctx.invoker(),
-            description: input.clone().description,
-            amount: input.clone().amount,
-            votes: 0,
-            contributers: vec![],
-            status: Status::Active,
-        },
-    ));
 
-    logger.log(&DAOEvent::ProposalCreated {
-        proposal_id,
-        description,
+    ensure_not_dissolved(state)?;
+    if amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
+    }
+
+    // Deducting the operations fee; only the net amount counts as deposit and voting power.
+    let fee = Amount::from_micro_ccd(
+        (u128::from(amount.micro_ccd()) * u128::from(state.config.fee_bps) / u128::from(MAX_BPS))
+            as u64,
+    );
+    let net = amount - fee;
+    let micro_ccd_amount = net.micro_ccd();
+    state.fees = checked_add_amounts(state.fees, fee)?;
+
+    state.total_deposited = checked_add_amounts(state.total_deposited, net)?;
+    let mut exists = false;
+
+    // Check if the sender already exists in the members vector.
+    for (account, member) in state.members.iter_mut() {
+        if *account == sender {
+            member.power = member
+                .power
+                .checked_add(micro_ccd_amount)
+                .ok_or(DAOError::Overflow)?;
+            member.deposited = checked_add_amounts(member.deposited, net)?;
+            exists = true;
+            break;
+        }
+    }
+
+    if !exists {
+        // New members have to deposit at least the minimum deposit.
+        if net < state.config.min_deposit {
+            return Err(DAOError::BelowMinDeposit.into());
+        }
+
+        // If the sender is not found, add a new entry.
+        state.members.push((
+            sender,
+            Member {
+                power: micro_ccd_amount,
+                deposited: net,
+            },
+        ));
+    }
+
+    if fee > Amount::zero() {
+        logger.log(&DAOEvent::FeeCharged {
+            donor: sender,
+            amount,
+            fee,
+        })?;
+    }
+
+    // Minting the donation receipt.
+    let token_id = TokenIdU32(state.receipts.len() as u32);
+    let receipt = Receipt {
+        owner: sender,
         amount,
-    })?;
+        timestamp: ctx.metadata().slot_time(),
+    };
+    logger.log(&ContractEvent::Mint(MintEvent {
+        token_id,
+        amount: TokenAmountU8(1),
+        owner: receipt.owner,
+    }))?;
+    logger.log(&ContractEvent::TokenMetadata(TokenMetadataEvent {
+        token_id,
+        metadata_url: receipt.metadata_url(token_id, ctx.self_address()),
+    }))?;
+    state.receipts.push((token_id, receipt));
 
     Ok(())
 }
 
-/// Function to vote on a proposal; can only be invoked by members.
+/// Function to claim the share of the treasury of a dissolved DAO, pro rata to the deposits of the member;
+/// can only be invoked by members, once.
 #[receive(
     contract = "DAO",
-    name = "vote",
-    parameter = "VoteInput",
+    name = "claim_share",
     error = "DAOError",
     mutable,
     enable_logger
 )]
-fn dao_vote(
+fn dao_claim_share(
     ctx: &ReceiveContext,
     host: &mut Host<DAOState>,
     logger: &mut Logger,
 ) -> ReceiveResult<()> {
-    let input: VoteInput = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    let voter = ctx.invoker();
+    let member = ctx.sender();
 
-    // Checking whether the invoker has enough power to vote.
-    if state.members.is_empty()
-        || !state
-            .members
-            .iter()
-            .any(|m| m.0 == voter && m.1 > 0 && m.1 >= input.votes)
-    {
-        return Err(DAOError::Unauthorized.into());
+    let dissolution = state.dissolution.as_mut().ok_or(DAOError::NotDissolved)?;
+    if dissolution.claimants.contains(&member) {
+        return Err(DAOError::AlreadyClaimed.into());
     }
 
-    let proposal_data = state
-        .proposals
-        .get_mut(input.proposal_id as usize)
-        .ok_or(DAOError::ProposalNotFound)?;
+    let (_, data) = state
+        .members
+        .iter()
+        .find(|(address, _)| *address == member)
+        .ok_or(DAOError::NotAMember)?;
+    let amount = dissolution.share_of(data.deposited)?;
 
-    proposal_data.1.votes += input.votes;
-    let mut exists = false;
+    dissolution.claimants.push(member);
+    dissolution.claimed = checked_add_amounts(dissolution.claimed, amount)?;
+    dissolution.claimed_deposits =
+        checked_add_amounts(dissolution.claimed_deposits, data.deposited)?;
+    pay_out(host, &member, amount)?;
 
-    // Checking whether the invoker has voted already.
-    for (v, votes) in proposal_data.1.contributers.iter_mut() {
-        if *v == voter {
-            // Incrementing the votes if already voted.
-            *votes += input.votes;
-            exists = true;
-            break;
-        }
-    }
+    logger.log(&DAOEvent::ShareClaimed { member, amount })?;
 
-    if !exists {
-        proposal_data.1.contributers.push((voter, input.votes));
-    }
+    Ok(())
+}
 
-    logger.log(&DAOEvent::Voted {
-        proposal_id: input.proposal_id,
-        voter,
-        total_votes: proposal_data.1.votes,
-    })?;
+/// Function to fetch the distribution of the treasury of a dissolved DAO.
+#[receive(
+    contract = "DAO",
+    name = "dissolution",
+    return_value = "Option<Dissolution>",
+    error = "DAOError"
+)]
+fn dao_dissolution(
+    _ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> ReceiveResult<Option<Dissolution>> {
+    Ok(host.state().dissolution.clone())
+}
 
-    // Decrementing the voting power of the voter.
-    for (account, power) in state.members.iter_mut() {
-        if *account == voter {
-            *power -= input.votes;
-        }
-    }
+/// Function to claim the refunds owed to a contract that failed to receive them; can only be invoked
+/// by the contract itself, which receives the refunds through the given entrypoint.
+#[receive(
+    contract = "DAO",
+    name = "claim_refund",
+    parameter = "ClaimRefundInput",
+    error = "DAOError",
+    mutable,
+    enable_logger
+)]
+fn dao_claim_refund(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: ClaimRefundInput = ctx.parameter_cursor().get()?;
+    let recipient = match ctx.sender() {
+        Address::Contract(contract) => contract,
+        Address::Account(_) => return Err(DAOError::Unauthorized.into()),
+    };
 
-    // Checking whether the threshold has reached.
-    if proposal_data.1.votes >= proposal_data.1.amount.micro_ccd() {
-        // Approve the proposal if threshold has reached.
-        proposal_data.1.status = Status::Approved
-    }
+    let owed = &mut host.state_mut().owed;
+    let index = owed
+        .iter()
+        .position(|(address, _)| *address == recipient)
+        .ok_or(DAOError::NothingOwed)?;
+    let (_, amount) = owed.remove(index);
+
+    host.invoke_contract(
+        &recipient,
+        &(),
+        input.entrypoint.as_entrypoint_name(),
+        amount,
+    )?;
+
+    logger.log(&DAOEvent::RefundClaimed { recipient, amount })?;
 
     Ok(())
 }
 
-/// Function to renounce votes on a proposal; can only be invoked by contributers (voters).
+/// Function to pay the accrued operations fees out to the operations account, allowed to anyone.
 #[receive(
     contract = "DAO",
-    name = "renounce",
-    parameter = "VoteInput",
+    name = "collect_fees",
     error = "DAOError",
     mutable,
     enable_logger
 )]
-fn dao_renounce(
-    ctx: &ReceiveContext,
+fn dao_collect_fees(
+    _ctx: &ReceiveContext,
     host: &mut Host<DAOState>,
     logger: &mut Logger,
 ) -> ReceiveResult<()> {
-    let input: VoteInput = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
-    let voter = ctx.invoker();
-    let mut renounce = 0;
+    let account = state
+        .config
+        .operations_account
+        .ok_or(DAOError::NoOperationsAccount)?;
+    let amount = state.fees;
 
-    // Checking whether the invoker is a member.
-    if state.members.is_empty() || !state.members.iter().any(|m| m.0 == voter) {
-        return Err(DAOError::Unauthorized.into());
+    if amount == Amount::zero() {
+        return Err(DAOError::ZeroAmount.into());
     }
 
-    let proposal_data = state
-        .proposals
-        .get_mut(input.proposal_id as usize)
-        .ok_or(DAOError::ProposalNotFound)?;
+    state.fees = Amount::zero();
+    host.invoke_transfer(&account, amount)?;
+
+    logger.log(&DAOEvent::FeesCollected { account, amount })?;
+
+    Ok(())
+}
+
+/// Function to transfer donation receipts, as specified by CIS-2. Receipts are non-transferable,
+/// unless the DAO is configured otherwise.
+#[receive(
+    contract = "DAO",
+    name = "transfer",
+    parameter = "TransferParams<ContractTokenId, ContractTokenAmount>",
+    error = "ContractError",
+    mutable,
+    enable_logger
+)]
+fn dao_transfer(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> Result<(), ContractError> {
+    let TransferParams(transfers): TransferParams<ContractTokenId, ContractTokenAmount> =
+        ctx.parameter_cursor().get()?;
+    let sender = ctx.sender();
 
-    // Can't renounce if the proposal is approved.
-    if proposal_data.1.status == Status::Approved || proposal_data.1.status == Status::Collected {
-        return Err(DAOError::AlreadyApproved.into());
+    if !host.state().config.receipts_transferable {
+        return Err(DAOError::NonTransferable.into());
     }
 
-    for (v, votes) in proposal_data.1.contributers.iter_mut() {
-        if *v == voter {
-            if *votes > input.votes {
-                // Renounce a particular amount of votes.
-                renounce = input.votes;
-                *votes -= renounce;
-                proposal_data.1.votes -= renounce;
-            } else {
-                // Renounce all.
-                renounce = *votes;
-                *votes -= renounce;
-                proposal_data.1.votes -= renounce;
-            }
-        } else {
-            return Err(DAOError::Unauthorized.into());
+    for Transfer {
+        token_id,
+        amount,
+        from,
+        to,
+        data,
+    } in transfers
+    {
+        let state = host.state_mut();
+
+        // Only the owner or its operators can transfer.
+        if from != sender && !state.operators.contains(&(from, sender)) {
+            return Err(Cis2Error::Unauthorized);
+        }
+
+        let (_, receipt) = state
+            .receipts
+            .iter_mut()
+            .find(|(id, _)| *id == token_id)
+            .ok_or(Cis2Error::InvalidTokenId)?;
+        let balance = if receipt.owner == from { 1 } else { 0 };
+        if amount.0 > balance {
+            return Err(Cis2Error::InsufficientFunds);
+        }
+
+        let to_address = to.address();
+        if amount.0 == 1 {
+            receipt.owner = to_address;
+        }
+
+        logger.log(&ContractEvent::Transfer(TransferEvent {
+            token_id,
+            amount,
+            from,
+            to: to_address,
+        }))?;
+
+        // Notifying receiving contracts.
+        if let Receiver::Contract(address, function) = to {
+            let parameter = OnReceivingCis2Params {
+                token_id,
+                amount,
+                from,
+                data,
+            };
+            host.invoke_contract(
+                &address,
+                &parameter,
+                function.as_entrypoint_name(),
+                Amount::zero(),
+            )?;
         }
     }
 
-    logger.log(&DAOEvent::Renounced {
-        proposal_id: input.proposal_id,
-        voter,
-        total_votes: proposal_data.1.votes,
-    })?;
+    Ok(())
+}
 
-    // Incrementing the voting power of the voter.
-    for (account, power) in state.members.iter_mut() {
-        if *account == voter {
-            *power += renounce;
+/// Function to add or remove operators of the sender's donation receipts, as specified by CIS-2.
+#[receive(
+    contract = "DAO",
+    name = "updateOperator",
+    parameter = "UpdateOperatorParams",
+    error = "ContractError",
+    mutable,
+    enable_logger
+)]
+fn dao_update_operator(
+    ctx: &ReceiveContext,
+    host: &mut Host<DAOState>,
+    logger: &mut Logger,
+) -> Result<(), ContractError> {
+    let UpdateOperatorParams(updates) = ctx.parameter_cursor().get()?;
+    let owner = ctx.sender();
+    let state = host.state_mut();
+
+    for UpdateOperator { update, operator } in updates {
+        match update {
+            OperatorUpdate::Add => {
+                if !state.operators.contains(&(owner, operator)) {
+                    state.operators.push((owner, operator));
+                }
+            }
+            OperatorUpdate::Remove => state.operators.retain(|o| *o != (owner, operator)),
         }
+
+        logger.log(&ContractEvent::UpdateOperator(UpdateOperatorEvent {
+            update,
+            owner,
+            operator,
+        }))?;
     }
 
     Ok(())
 }
 
-/// Function to fetch all proposals.
+/// Function to query the balances of donation receipts, as specified by CIS-2.
 #[receive(
     contract = "DAO",
-    name = "all_proposals",
-    return_value = "Vec<(u64, Proposal)>",
-    error = "DAOError"
+    name = "balanceOf",
+    parameter = "BalanceOfQueryParams<ContractTokenId>",
+    return_value = "BalanceOfQueryResponse<ContractTokenAmount>",
+    error = "ContractError"
 )]
-fn dao_all_proposals(
-    _ctx: &ReceiveContext,
+fn dao_balance_of(
+    ctx: &ReceiveContext,
     host: &Host<DAOState>,
-) -> ReceiveResult<Vec<(u64, Proposal)>> {
-    Ok(host.state().proposals.clone())
+) -> Result<BalanceOfQueryResponse<ContractTokenAmount>, ContractError> {
+    let params: BalanceOfQueryParams<ContractTokenId> = ctx.parameter_cursor().get()?;
+    let mut response = Vec::with_capacity(params.queries.len());
+
+    for query in params.queries {
+        let (_, receipt) = host
+            .state()
+            .receipts
+            .iter()
+            .find(|(id, _)| *id == query.token_id)
+            .ok_or(Cis2Error::InvalidTokenId)?;
+        let balance = if receipt.owner == query.address { 1 } else { 0 };
+        response.push(TokenAmountU8(balance));
+    }
+
+    Ok(BalanceOfQueryResponse::from(response))
 }
 
-/// Function to fetch all members.
+/// Function to query operators of receipt owners, as specified by CIS-2.
 #[receive(
     contract = "DAO",
-    name = "all_members",
-    return_value = "Vec<(AccountAddress,u64)>",
-    error = "DAOError"
+    name = "operatorOf",
+    parameter = "OperatorOfQueryParams",
+    return_value = "OperatorOfQueryResponse",
+    error = "ContractError"
 )]
-fn dao_all_members(
-    _ctx: &ReceiveContext,
+fn dao_operator_of(
+    ctx: &ReceiveContext,
     host: &Host<DAOState>,
-) -> ReceiveResult<Vec<(AccountAddress, u64)>> {
-    Ok(host.state().members.clone())
+) -> Result<OperatorOfQueryResponse, ContractError> {
+    let params: OperatorOfQueryParams = ctx.parameter_cursor().get()?;
+    let operators = &host.state().operators;
+
+    Ok(OperatorOfQueryResponse::from(
+        params
+            .queries
+            .iter()
+            .map(|query| operators.contains(&(query.owner, query.address)))
+            .collect::<Vec<_>>(),
+    ))
 }
 
-/// Function to fetch voting power of a particular account.
+/// Function to query the metadata of donation receipts, as specified by CIS-2.
 #[receive(
     contract = "DAO",
-    name = "get_power",
-    parameter = "AddressInput",
-    return_value = "u64",
-    error = "DAOError"
+    name = "tokenMetadata",
+    parameter = "TokenMetadataQueryParams<ContractTokenId>",
+    return_value = "TokenMetadataQueryResponse",
+    error = "ContractError"
 )]
-fn dao_get_power(ctx: &ReceiveContext, host: &Host<DAOState>) -> ReceiveResult<u64> {
-    let input: AddressInput = ctx.parameter_cursor().get()?;
-    let state = host.state();
+fn dao_token_metadata(
+    ctx: &ReceiveContext,
+    host: &Host<DAOState>,
+) -> Result<TokenMetadataQueryResponse, ContractError> {
+    let params: TokenMetadataQueryParams<ContractTokenId> = ctx.parameter_cursor().get()?;
+    let mut response = Vec::with_capacity(params.queries.len());
 
-    for (address, power) in state.members.iter() {
-        if *address == input.address {
-            return Ok(*power);
-        }
+    for token_id in params.queries {
+        let (_, receipt) = host
+            .state()
+            .receipts
+            .iter()
+            .find(|(id, _)| *id == token_id)
+            .ok_or(Cis2Error::InvalidTokenId)?;
+        response.push(receipt.metadata_url(token_id, ctx.self_address()));
     }
 
-    Ok(0)
+    Ok(TokenMetadataQueryResponse::from(response))
 }
 
-/// Function to insert some CCD into DAO, allowed to anyone. This will grant membership in DAO.
-#[receive(contract = "DAO", name = "insert", mutable, payable)]
-fn dao_insert(
+/// Function to fetch the donation receipts owned by a particular address.
+#[receive(
+    contract = "DAO",
+    name = "receipts_of",
+    parameter = "AddressInput",
+    return_value = "Vec<(ContractTokenId, Receipt)>",
+    error = "DAOError"
+)]
+fn dao_receipts_of(
     ctx: &ReceiveContext,
-    host: &mut Host<DAOState>,
-    amount: Amount,
-) -> ReceiveResult<()> {
-    let invoker = ctx.invoker();
-    let micro_ccd_amount = amount.micro_ccd();
-    let members = &mut host.state_mut().members;
-
-    // Check if the invoker already exists in the members vector.
-    for (account, power) in members.iter_mut() {
-        if *account == invoker {
-            *power += micro_ccd_amount;
-            return Ok(());
-        }
-    }
+    host: &Host<DAOState>,
+) -> ReceiveResult<Vec<(ContractTokenId, Receipt)>> {
+    let input: AddressInput = ctx.parameter_cursor().get()?;
+    let owner = input.address;
 
-    // If the invoker is not found, add a new entry.
-    members.push((invoker, micro_ccd_amount));
-    Ok(())
+    Ok(host
+        .state()
+        .receipts
+        .iter()
+        .filter(|(_, receipt)| receipt.owner == owner)
+        .cloned()
+        .collect())
 }
 
 /// Function to withraw the fund for an approved proposal from contract; can only be invoked by the proposer.
+/// Payouts above the payout limit require the confirmations of the signers, and no payout may breach the reserve.
 #[receive(
     contract = "DAO",
     name = "withdraw",
@@ -368,16 +4257,26 @@ fn dao_insert(
 )]
 fn dao_withdraw(ctx: &ReceiveContext, host: &mut Host<DAOState>) -> ReceiveResult<()> {
     let input: WithdrawInput = ctx.parameter_cursor().get()?;
-    let caller = ctx.invoker();
+    let caller = ctx.sender();
 
     // Extract necessary information without borrowing state
-    let (proposal_status, proposal_amount) = {
+    let (proposal_status, approved_amount, euro, confirmations) = {
         let state = host.state();
         let mut found_proposal = None;
 
         for (id, p) in &state.proposals {
             if *id == input.proposal_id && p.proposer == caller {
-                found_proposal = Some((p.status.clone(), p.amount));
+                // Governance proposals have nothing to withdraw.
+                if p.kind != ProposalKind::Funding {
+                    return Err(DAOError::NotFunding.into());
+                }
+                // Only confirmations of the current signers count.
+                let confirmations = p
+                    .confirmations
+                    .iter()
+                    .filter(|s| state.signers.signers.contains(s))
+                    .count();
+                found_proposal = Some((p.status.clone(), p.amount, p.euro.clone(), confirmations));
                 break;
             }
         }
@@ -385,24 +4284,64 @@ fn dao_withdraw(ctx: &ReceiveContext, host: &mut Host<DAOState>) -> ReceiveResul
         found_proposal.ok_or(DAOError::Unauthorized)?
     };
 
+    // Proposals denominated in EUR are paid out at the current exchange rate, within the slippage bound.
+    let proposal_amount = match &euro {
+        Some(euro) => {
+            let amount = euro.to_ccd(&host.exchange_rates())?;
+            euro.check_slippage(approved_amount, amount)?;
+            amount
+        }
+        None => approved_amount,
+    };
+
     // Perform checks
     match proposal_status {
         Status::Approved => {
-            if proposal_amount > host.self_balance() {
+            // The payout may use the amount committed at approval and the free balance, but nothing
+            // else that is locked.
+            let state = host.state();
+            let balance = host.self_balance();
+            let locked_elsewhere = state
+                .locked()?
+                .checked_sub(approved_amount)
+                .ok_or(DAOError::Overflow)?;
+            if checked_add_amounts(locked_elsewhere, proposal_amount)? > balance {
+                let unreserved = locked_elsewhere
+                    .checked_sub(state.reserve()?)
+                    .ok_or(DAOError::Overflow)?;
+                if checked_add_amounts(unreserved, proposal_amount)? <= balance {
+                    return Err(DAOError::ReserveBreached.into());
+                }
                 return Err(DAOError::InsufficientBalance.into());
             }
+            if state
+                .config
+                .payout_limit
+                .is_some_and(|limit| proposal_amount > limit)
+                && (state.signers.required == 0 || confirmations < state.signers.required as usize)
+            {
+                return Err(DAOError::ConfirmationsMissing.into());
+            }
         }
-        Status::Collected => return Err(DAOError::AmountCollected.into()),
+        Status::Collected | Status::Reported => return Err(DAOError::AmountCollected.into()),
         _ => return Err(DAOError::NotApproved.into()),
     }
 
     // Perform mutable operation
     let state = host.state_mut();
+    let report_deadline = ctx
+        .metadata()
+        .slot_time()
+        .checked_add(state.config.report_period)
+        .ok_or(DAOError::Overflow)?;
     for (id, p) in &mut state.proposals {
         if *id == input.proposal_id {
             // Perform the transfer
             p.status = Status::Collected;
-            return Ok(host.invoke_transfer(&caller, proposal_amount)?);
+            p.amount = proposal_amount;
+            p.report_deadline = Some(report_deadline);
+            state.disbursed = checked_add_amounts(state.disbursed, proposal_amount)?;
+            return pay_out(host, &caller, proposal_amount);
         }
     }
 
@@ -0,0 +1,250 @@
+use crate::{DAOConfig, DAOInfo};
+use concordium_std::*;
+use core::fmt::Debug;
+
+/// Name of the init function every registered DAO has to be instantiated from.
+const DAO_INIT_NAME: &str = "init_DAO";
+
+/// The state of the registry.
+#[derive(Serialize, SchemaType)]
+pub struct RegistryState {
+    pub daos: Vec<(ContractAddress, DAOEntry)>,
+}
+
+/// A DAO instance known to the registry.
+#[derive(Debug, Clone, Serialize, SchemaType, PartialEq, Eq)]
+pub struct DAOEntry {
+    /// Name of the DAO.
+    pub name: String,
+    /// Current parameters of the DAO, refreshed whenever its config is updated.
+    pub config: DAOConfig,
+    /// The one who has initialized the DAO.
+    pub creator: AccountAddress,
+    /// Time of the registration.
+    pub registered_at: Timestamp,
+}
+
+/// Input for [`DAORegistry.register`] and [`DAORegistry.refresh`].
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct RegisterInput {
+    pub dao: ContractAddress,
+}
+
+/// Input for [`DAORegistry.search`]; every given field has to match.
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub struct SearchInput {
+    /// Part of the name of the DAO.
+    pub name: Option<String>,
+    pub creator: Option<AccountAddress>,
+}
+
+///  Registry errors.
+#[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
+pub enum RegistryError {
+    /// Failed parsing the parameter.
+    #[from(ParseError)]
+    ParseParams,
+    NotADAO,
+    AlreadyRegistered,
+    Unauthorized,
+    NotRegistered,
+    /// The DAO rejected being linked to the registry.
+    InvokeContractError,
+}
+
+impl<T> From<CallContractError<T>> for RegistryError {
+    fn from(_err: CallContractError<T>) -> Self {
+        RegistryError::InvokeContractError
+    }
+}
+
+/// Events emitted from the registry contract.
+#[derive(Debug, Serialize, SchemaType, PartialEq, Eq)]
+pub enum RegistryEvent {
+    Registered {
+        dao: ContractAddress,
+        name: String,
+        creator: AccountAddress,
+    },
+    Refreshed {
+        dao: ContractAddress,
+    },
+}
+
+/// Initialize the registry without any DAOs.
+#[init(contract = "DAORegistry")]
+fn registry_init(
+    _ctx: &InitContext,
+    _state_builder: &mut StateBuilder,
+) -> InitResult<RegistryState> {
+    Ok(RegistryState { daos: vec![] })
+}
+
+/// Function to register a DAO; can only be invoked by the creator of the DAO, right after its
+/// initialization (init functions are unable to invoke other contracts). The DAO has to be
+/// an instance of the `DAO` contract from the same module as the registry, and is linked to the
+/// registry so that it refreshes its entry on config updates.
+#[receive(
+    contract = "DAORegistry",
+    name = "register",
+    parameter = "RegisterInput",
+    error = "RegistryError",
+    mutable,
+    enable_logger
+)]
+fn registry_register(
+    ctx: &ReceiveContext,
+    host: &mut Host<RegistryState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RegisterInput = ctx.parameter_cursor().get()?;
+    let dao = input.dao;
+
+    // Checking whether the DAO was instantiated from the `DAO` contract of this module.
+    let module = host
+        .contract_module_reference(ctx.self_address())
+        .map_err(|_| RegistryError::NotADAO)?;
+    let same_module = host.contract_module_reference(dao) == Ok(module);
+    let is_dao = host
+        .contract_name(dao)
+        .map(|name| name.as_contract_name().get_chain_name() == DAO_INIT_NAME)
+        .unwrap_or(false);
+    if !same_module || !is_dao {
+        return Err(RegistryError::NotADAO.into());
+    }
+
+    if host.state().daos.iter().any(|(address, _)| *address == dao) {
+        return Err(RegistryError::AlreadyRegistered.into());
+    }
+
+    let info = fetch_info(host, dao)?;
+
+    // Only the creator of the DAO can register it.
+    if ctx.sender() != Address::Account(info.origin) {
+        return Err(RegistryError::Unauthorized.into());
+    }
+
+    host.state_mut().daos.push((
+        dao,
+        DAOEntry {
+            name: info.name.clone(),
+            config: info.config,
+            creator: info.origin,
+            registered_at: ctx.metadata().slot_time(),
+        },
+    ));
+
+    host.invoke_contract(
+        &dao,
+        &(),
+        EntrypointName::new_unchecked("set_registry"),
+        Amount::zero(),
+    )?;
+
+    logger.log(&RegistryEvent::Registered {
+        dao,
+        name: info.name,
+        creator: info.origin,
+    })?;
+
+    Ok(())
+}
+
+/// Function to refresh the entry of a registered DAO from its current info; can be invoked by
+/// anyone, and is invoked by the DAO itself whenever its config is updated.
+#[receive(
+    contract = "DAORegistry",
+    name = "refresh",
+    parameter = "RegisterInput",
+    error = "RegistryError",
+    mutable,
+    enable_logger
+)]
+fn registry_refresh(
+    ctx: &ReceiveContext,
+    host: &mut Host<RegistryState>,
+    logger: &mut Logger,
+) -> ReceiveResult<()> {
+    let input: RegisterInput = ctx.parameter_cursor().get()?;
+    let dao = input.dao;
+
+    if !host.state().daos.iter().any(|(address, _)| *address == dao) {
+        return Err(RegistryError::NotRegistered.into());
+    }
+
+    let info = fetch_info(host, dao)?;
+
+    let (_, entry) = host
+        .state_mut()
+        .daos
+        .iter_mut()
+        .find(|(address, _)| *address == dao)
+        .ok_or(RegistryError::NotRegistered)?;
+    entry.name = info.name;
+    entry.config = info.config;
+
+    logger.log(&RegistryEvent::Refreshed { dao })?;
+
+    Ok(())
+}
+
+/// Fetches the name, parameters and creator of a DAO.
+fn fetch_info(host: &Host<RegistryState>, dao: ContractAddress) -> Result<DAOInfo, RegistryError> {
+    host.invoke_contract_read_only(
+        &dao,
+        &(),
+        EntrypointName::new_unchecked("info"),
+        Amount::zero(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|mut rv| rv.get().ok())
+    .ok_or(RegistryError::NotADAO)
+}
+
+/// Function to fetch all registered DAOs.
+#[receive(
+    contract = "DAORegistry",
+    name = "list",
+    return_value = "Vec<(ContractAddress, DAOEntry)>",
+    error = "RegistryError"
+)]
+fn registry_list(
+    _ctx: &ReceiveContext,
+    host: &Host<RegistryState>,
+) -> ReceiveResult<Vec<(ContractAddress, DAOEntry)>> {
+    Ok(host.state().daos.clone())
+}
+
+/// Function to fetch the registered DAOs matching a name and/or a creator.
+#[receive(
+    contract = "DAORegistry",
+    name = "search",
+    parameter = "SearchInput",
+    return_value = "Vec<(ContractAddress, DAOEntry)>",
+    error = "RegistryError"
+)]
+fn registry_search(
+    ctx: &ReceiveContext,
+    host: &Host<RegistryState>,
+) -> ReceiveResult<Vec<(ContractAddress, DAOEntry)>> {
+    let input: SearchInput = ctx.parameter_cursor().get()?;
+
+    Ok(host
+        .state()
+        .daos
+        .iter()
+        .filter(|(_, entry)| {
+            let name_matches = match &input.name {
+                Some(name) => entry.name.contains(name.as_str()),
+                None => true,
+            };
+            let creator_matches = match input.creator {
+                Some(creator) => entry.creator == creator,
+                None => true,
+            };
+            name_matches && creator_matches
+        })
+        .cloned()
+        .collect())
+}
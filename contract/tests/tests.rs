@@ -1,6 +1,8 @@
+use concordium_cis2::*;
 use concordium_smart_contract_testing::*;
+use concordium_std::{Deserial, Serial};
 use concordium_std_derive::*;
-use contract::*;
+use contract::{registry::*, *};
 
 const ACC_ADDR_OWNER: AccountAddress =
     account_address!("2xBpaHottqhwFZURMZW4uZduQvpxNDSy46iXMYs9kceNGaPpZX");
@@ -13,7 +15,42 @@ const SIGNER: Signer = Signer::with_one_key();
 /// The initial balance for the test accounts.
 const ACC_INITIAL_BALANCE: Amount = Amount::from_ccd(10_000);
 
+/// The name of the test DAO.
+const DAO_NAME: &str = "Kerala Relief DAO";
+
+/// The voting period of the test DAO, in milliseconds.
+const VOTING_PERIOD_MILLIS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// The period for submitting impact reports in the test DAO, in milliseconds.
+const REPORT_PERIOD_MILLIS: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// The configuration the test DAO is initialized with.
+fn dao_config() -> DAOConfig {
+    DAOConfig {
+        proposal_bond: Amount::zero(),
+        voting_period: Duration::from_millis(VOTING_PERIOD_MILLIS),
+        min_support: 1,
+        report_period: Duration::from_millis(REPORT_PERIOD_MILLIS),
+        dispute_quorum: 1_000_000,
+        min_deposit: Amount::zero(),
+        tiers: vec![],
+        proposal_gate: None,
+        payout_limit: None,
+        governance_threshold: 1_000_000,
+        reserve_ratio_bps: 0,
+        reserve_floor: Amount::zero(),
+        receipts_transferable: false,
+        fee_bps: 0,
+        operations_account: None,
+        reveal_period: None,
+    }
+}
+
 fn setup_chain_and_contract() -> (Chain, ContractInitSuccess) {
+    setup_chain_and_contract_with(dao_config())
+}
+
+fn setup_chain_and_contract_with(config: DAOConfig) -> (Chain, ContractInitSuccess) {
     let mut chain = Chain::new();
 
     chain.create_account(Account::new(ACC_ADDR_OWNER, ACC_INITIAL_BALANCE));
@@ -33,7 +70,11 @@ fn setup_chain_and_contract() -> (Chain, ContractInitSuccess) {
                 amount: Amount::zero(),
                 mod_ref: deployment.module_reference,
                 init_name: OwnedContractName::new_unchecked("init_DAO".to_string()),
-                param: OwnedParameter::empty(),
+                param: OwnedParameter::from_serial(&InitParams {
+                    name: DAO_NAME.to_string(),
+                    config,
+                })
+                .expect("Init params"),
             },
         )
         .expect("Initialization should always succeed");
@@ -41,6 +82,143 @@ fn setup_chain_and_contract() -> (Chain, ContractInitSuccess) {
     (chain, initialization)
 }
 
+/// Moves the state of the chain over to a chain with another exchange rate of microCCD per euro.
+fn reprice(chain: Chain, micro_ccd_per_euro: u64) -> Chain {
+    let mut repriced = Chain::builder()
+        .micro_ccd_per_euro(ExchangeRate::new_unchecked(micro_ccd_per_euro, 1))
+        .block_time(chain.block_time())
+        .build()
+        .expect("Chain builds");
+    repriced.accounts = chain.accounts;
+    repriced.modules = chain.modules;
+    repriced.contracts = chain.contracts;
+    repriced
+}
+
+/// Decodes a percent-encoded string.
+fn percent_decode(encoded: &str) -> String {
+    let mut bytes = vec![];
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(&tail[..2]).expect("Hex digits");
+            bytes.push(u8::from_str_radix(hex, 16).expect("Percent-encoded byte"));
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).expect("UTF-8 string")
+}
+
+/// Sends an update to the given entrypoint of the DAO.
+fn update<P: Serial>(
+    chain: &mut Chain,
+    sender: AccountAddress,
+    init: &ContractInitSuccess,
+    entrypoint: &str,
+    amount: Amount,
+    param: &P,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    chain.contract_update(
+        SIGNER,
+        sender,
+        Address::Account(sender),
+        Energy::from(10_000),
+        UpdateContractPayload {
+            address: init.contract_address,
+            amount,
+            receive_name: OwnedReceiveName::new_unchecked(format!("DAO.{entrypoint}")),
+            message: OwnedParameter::from_serial(param).expect("Serialize parameter"),
+        },
+    )
+}
+
+/// Invokes the given view entrypoint of the DAO and parses its return value.
+fn view<P: Serial, R: Deserial>(
+    chain: &Chain,
+    init: &ContractInitSuccess,
+    entrypoint: &str,
+    param: &P,
+) -> Result<R, DAOError> {
+    let invoke = chain.contract_invoke(
+        ACC_ADDR_OWNER,
+        Address::Account(ACC_ADDR_OWNER),
+        Energy::from(10_000),
+        UpdateContractPayload {
+            amount: Amount::zero(),
+            receive_name: OwnedReceiveName::new_unchecked(format!("DAO.{entrypoint}")),
+            address: init.contract_address,
+            message: OwnedParameter::from_serial(param).expect("Serialize parameter"),
+        },
+    );
+    match invoke {
+        Ok(success) => Ok(success
+            .parse_return_value()
+            .expect("Deserialize return value")),
+        Err(err) => Err(err.parse_return_value().expect("Deserialize Error")),
+    }
+}
+
+/// Initializes a second DAO, created by the other account.
+fn init_target_dao(chain: &mut Chain, init: &ContractInitSuccess) -> ContractInitSuccess {
+    let module_reference = chain
+        .get_contract(init.contract_address)
+        .expect("DAO exists")
+        .module_reference;
+    chain
+        .contract_init(
+            SIGNER,
+            ACC_ADDR_OTHER,
+            Energy::from(10_000),
+            InitContractPayload {
+                amount: Amount::zero(),
+                mod_ref: module_reference,
+                init_name: OwnedContractName::new_unchecked("init_DAO".to_string()),
+                param: OwnedParameter::from_serial(&InitParams {
+                    name: "Wayanad Relief DAO".to_string(),
+                    config: dao_config(),
+                })
+                .expect("Init params"),
+            },
+        )
+        .expect("Initialization should always succeed")
+}
+
+/// Executes a contract call through a governance proposal of the DAO, voted by the owner.
+fn invoke_through_governance(
+    chain: &mut Chain,
+    init: &ContractInitSuccess,
+    proposal_id: u64,
+    call: ContractCall,
+) -> ContractInvokeSuccess {
+    update(
+        chain,
+        ACC_ADDR_OWNER,
+        init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Call the Wayanad Relief DAO".to_string(),
+            action: GovernanceAction::Invoke(call),
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    update(
+        chain,
+        ACC_ADDR_OWNER,
+        init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote")
+}
+
 fn check_event(update: &ContractInvokeSuccess, event: DAOEvent) {
     let events: Vec<DAOEvent> = update
         .events()
@@ -116,7 +294,8 @@ fn test_power() {
                 amount: Amount::zero(),
                 receive_name: OwnedReceiveName::new_unchecked("DAO.get_power".to_string()),
                 address: init.contract_address,
-                message: OwnedParameter::from_serial(&ACC_ADDR_OWNER).expect("Get power"),
+                message: OwnedParameter::from_serial(&Address::Account(ACC_ADDR_OWNER))
+                    .expect("Get power"),
             },
         )
         .expect("Get power");
@@ -133,7 +312,8 @@ fn test_power() {
                 amount: Amount::zero(),
                 receive_name: OwnedReceiveName::new_unchecked("DAO.get_power".to_string()),
                 address: init.contract_address,
-                message: OwnedParameter::from_serial(&ACC_ADDR_OTHER).expect("Get power"),
+                message: OwnedParameter::from_serial(&Address::Account(ACC_ADDR_OTHER))
+                    .expect("Get power"),
             },
         )
         .expect("Get power");
@@ -235,23 +415,49 @@ fn test_all_proposals() {
         (
             0,
             Proposal {
-                proposer: ACC_ADDR_OWNER,
+                proposer: Address::Account(ACC_ADDR_OWNER),
                 description: input.description.clone(),
                 amount: input.amount,
                 votes: 0,
                 contributers: vec![],
                 status: Status::Active,
+                bond: Amount::zero(),
+                deadline: Timestamp::from_timestamp_millis(VOTING_PERIOD_MILLIS),
+                donations: vec![],
+                report_deadline: None,
+                report: None,
+                revision: 0,
+                previous_description_hash: None,
+                confirmations: vec![],
+                kind: ProposalKind::Funding,
+                euro: None,
+                reveal_deadline: None,
+                commitments: vec![],
+                config_revision: 0,
             },
         ),
         (
             1,
             Proposal {
-                proposer: ACC_ADDR_OTHER,
+                proposer: Address::Account(ACC_ADDR_OTHER),
                 description: input.description,
                 amount: input.amount,
                 votes: 0,
                 contributers: vec![],
                 status: Status::Active,
+                bond: Amount::zero(),
+                deadline: Timestamp::from_timestamp_millis(VOTING_PERIOD_MILLIS),
+                donations: vec![],
+                report_deadline: None,
+                report: None,
+                revision: 0,
+                previous_description_hash: None,
+                confirmations: vec![],
+                kind: ProposalKind::Funding,
+                euro: None,
+                reveal_deadline: None,
+                commitments: vec![],
+                config_revision: 0,
             },
         ),
     ];
@@ -310,9 +516,12 @@ fn test_authorized_vote() {
         )
         .expect("Fetch all members");
 
-    let return_value: Vec<(AccountAddress, u64)> =
+    let return_value: Vec<(Address, u64)> =
         invoke.parse_return_value().expect("Members return value");
-    let expected_value = vec![(ACC_ADDR_OWNER, 10_000_000), (ACC_ADDR_OTHER, 10_000_000)];
+    let expected_value = vec![
+        (Address::Account(ACC_ADDR_OWNER), 10_000_000),
+        (Address::Account(ACC_ADDR_OTHER), 10_000_000),
+    ];
 
     assert_eq!(return_value, expected_value);
 
@@ -360,7 +569,9 @@ fn test_authorized_vote() {
         &update,
         DAOEvent::Voted {
             proposal_id: 0,
-            voter: ACC_ADDR_OWNER,
+            voter: Address::Account(ACC_ADDR_OWNER),
+            accepted: 100,
+            returned: 0,
             total_votes: 100,
         },
     );
@@ -384,12 +595,25 @@ fn test_authorized_vote() {
     let expected_value = vec![(
         0,
         Proposal {
-            proposer: ACC_ADDR_OWNER,
+            proposer: Address::Account(ACC_ADDR_OWNER),
             description: input.description.clone(),
             amount: input.amount,
             votes: 100,
-            contributers: vec![(ACC_ADDR_OWNER, 100)],
+            contributers: vec![(Address::Account(ACC_ADDR_OWNER), 100)],
             status: Status::Active,
+            bond: Amount::zero(),
+            deadline: Timestamp::from_timestamp_millis(VOTING_PERIOD_MILLIS),
+            donations: vec![],
+            report_deadline: None,
+            report: None,
+            revision: 0,
+            previous_description_hash: None,
+            confirmations: vec![],
+            kind: ProposalKind::Funding,
+            euro: None,
+            reveal_deadline: None,
+            commitments: vec![],
+            config_revision: 0,
         },
     )];
 
@@ -913,12 +1137,25 @@ fn test_approved_withdraw() {
     let expected_value = vec![(
         0,
         Proposal {
-            proposer: ACC_ADDR_OTHER,
+            proposer: Address::Account(ACC_ADDR_OTHER),
             description: input.description.clone(),
             amount: input.amount,
             votes: 100_000,
-            contributers: vec![(ACC_ADDR_OWNER, input.amount.micro_ccd())],
+            contributers: vec![(Address::Account(ACC_ADDR_OWNER), input.amount.micro_ccd())],
             status: Status::Collected,
+            bond: Amount::zero(),
+            deadline: Timestamp::from_timestamp_millis(VOTING_PERIOD_MILLIS),
+            donations: vec![],
+            report_deadline: Some(Timestamp::from_timestamp_millis(REPORT_PERIOD_MILLIS)),
+            report: None,
+            revision: 0,
+            previous_description_hash: None,
+            confirmations: vec![],
+            kind: ProposalKind::Funding,
+            euro: None,
+            reveal_deadline: None,
+            commitments: vec![],
+            config_revision: 0,
         },
     )];
 
@@ -1011,7 +1248,7 @@ fn test_renounce() {
         &update,
         DAOEvent::Renounced {
             proposal_id: 0,
-            voter: ACC_ADDR_OWNER,
+            voter: Address::Account(ACC_ADDR_OWNER),
             total_votes: 0,
         },
     );
@@ -1097,3 +1334,5260 @@ fn test_invalid_renounce() {
     let rv: DAOError = update.parse_return_value().expect("Deserialize Error");
     assert_eq!(rv, DAOError::AlreadyApproved);
 }
+
+#[test]
+fn test_proposal_bond() {
+    let bond = Amount::from_ccd(1);
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        proposal_bond: bond,
+        ..dao_config()
+    });
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+
+    // Creating a proposal without the bond fails.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect_err("Update fails without bond");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::InvalidBond);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        bond,
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(11))
+    );
+
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: 100_000,
+    };
+
+    // The bond is refunded on approval.
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+    assert!(upd
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OTHER && amount == bond));
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(10))
+    );
+}
+
+#[test]
+fn test_expire() {
+    let bond = Amount::from_ccd(1);
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        proposal_bond: bond,
+        ..dao_config()
+    });
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+
+    // One proposal gets support, the other one doesn't.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        bond,
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        bond,
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: 100,
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "expire",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails during voting period");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::VotingOpen);
+
+    chain
+        .tick_block_time(Duration::from_millis(VOTING_PERIOD_MILLIS))
+        .expect("Block time advances");
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect_err("Update fails after voting period");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::VotingClosed);
+
+    // Supported proposal gets its bond back and its votes are released.
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "expire",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with expiry");
+    assert!(upd
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OTHER && amount == bond));
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.get_power".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::from_serial(&Address::Account(ACC_ADDR_OWNER))
+                    .expect("Get power"),
+            },
+        )
+        .expect("Get power");
+    let return_value: u64 = invoke.parse_return_value().expect("Power return value");
+    assert_eq!(return_value, Amount::from_ccd(10).micro_ccd());
+
+    // Unsupported proposal forfeits its bond.
+    let id = ProposalIdInput { proposal_id: 1 };
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "expire",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with expiry");
+    assert_eq!(upd.account_transfers().count(), 0);
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(11))
+    );
+}
+
+#[test]
+fn test_veto() {
+    let bond = Amount::from_ccd(1);
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        proposal_bond: bond,
+        ..dao_config()
+    });
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        bond,
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "veto",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails for non-guardian");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "veto",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with veto");
+    let events: Vec<DAOEvent> = upd
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Vetoed { proposal_id: 0 },
+            DAOEvent::BondForfeited {
+                proposal_id: 0,
+                amount: bond,
+            },
+        ]
+    );
+    assert_eq!(chain.contract_balance(init.contract_address), Some(bond));
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "expire",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails for vetoed proposal");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::ProposalClosed);
+
+    // The guardians allowed to veto are set through governance.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    for input in [
+        GovernanceInput {
+            description: "Hand the veto over".to_string(),
+            action: GovernanceAction::SetGuardians(vec![ACC_ADDR_OTHER]),
+        },
+        GovernanceInput {
+            description: "Extend the voting period".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                voting_period: Duration::from_millis(2 * VOTING_PERIOD_MILLIS),
+                ..dao_config()
+            }),
+        },
+    ] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_governance_proposal",
+            bond,
+            &input,
+        )
+        .expect("Update succeeds with new governance proposal");
+    }
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with vote");
+
+    let guardians: Vec<AccountAddress> =
+        view(&chain, &init, "guardians", &()).expect("View guardians");
+    assert_eq!(guardians, vec![ACC_ADDR_OTHER]);
+
+    let id = ProposalIdInput { proposal_id: 2 };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "veto",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails for a former guardian");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "veto",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with veto by a guardian");
+}
+
+#[test]
+fn test_donate_to_proposal() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::from_ccd(5),
+    };
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(2),
+        &id,
+    )
+    .expect("Update succeeds with new donation");
+    check_event(
+        &upd,
+        DAOEvent::Donated {
+            proposal_id: 0,
+            donor: Address::Account(ACC_ADDR_OWNER),
+            amount: Amount::from_ccd(2),
+        },
+    );
+
+    // The surplus beyond the threshold is returned and the proposal is approved.
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(4),
+        &id,
+    )
+    .expect("Update succeeds with new donation");
+    assert!(upd
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OWNER && amount == Amount::from_ccd(1)));
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(5))
+    );
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.all_proposals".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("Fetch all proposals");
+    let return_value: Vec<(u64, Proposal)> =
+        invoke.parse_return_value().expect("Proposals return value");
+    assert_eq!(return_value[0].1.status, Status::Approved);
+    assert_eq!(
+        return_value[0].1.donations,
+        vec![(Address::Account(ACC_ADDR_OWNER), Amount::from_ccd(5))]
+    );
+
+    // The earmarked donations are released with the payout.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with withdrawal");
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::zero())
+    );
+}
+
+#[test]
+fn test_cancel_refunds_donors() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::from_ccd(5),
+    };
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(2),
+        &id,
+    )
+    .expect("Update succeeds with new donation");
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "cancel_proposal",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails for non-proposer");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "cancel_proposal",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with cancellation");
+    assert!(upd
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OWNER && amount == Amount::from_ccd(2)));
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(10))
+    );
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(2),
+        &id,
+    )
+    .expect_err("Update fails for cancelled proposal");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::ProposalClosed);
+}
+
+#[test]
+fn test_treasury() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::from_ccd(3),
+    };
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: Amount::from_ccd(3).micro_ccd(),
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+
+    let id = ProposalIdInput { proposal_id: 1 };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(1),
+        &id,
+    )
+    .expect("Update succeeds with new donation");
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.treasury".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("Fetch treasury");
+    let return_value: TreasuryView = invoke.parse_return_value().expect("Treasury return value");
+    assert_eq!(
+        return_value,
+        TreasuryView {
+            balance: Amount::from_ccd(11),
+            total_deposited: Amount::from_ccd(10),
+            outstanding_power: Amount::from_ccd(7).micro_ccd(),
+            committed: Amount::from_ccd(3),
+            held: Amount::from_ccd(1),
+            in_rounds: Amount::zero(),
+            disbursed: Amount::zero(),
+            reserve: Amount::zero(),
+            fees: Amount::zero(),
+            owed: Amount::zero(),
+            free_balance: Amount::from_ccd(7),
+        }
+    );
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with withdrawal");
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.treasury".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("Fetch treasury");
+    let return_value: TreasuryView = invoke.parse_return_value().expect("Treasury return value");
+    assert_eq!(return_value.committed, Amount::zero());
+    assert_eq!(return_value.disbursed, Amount::from_ccd(3));
+    assert_eq!(return_value.free_balance, Amount::from_ccd(7));
+}
+
+#[test]
+fn test_renounce_multiple_voters() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: 100,
+    };
+
+    // A member who hasn't voted can't renounce.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "renounce",
+        Amount::zero(),
+        &v,
+    )
+    .expect_err("Update fails for non-voter");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::NotAVoter);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+
+    // The second voter can renounce part of their votes.
+    let partial = VoteInput {
+        proposal_id: 0,
+        votes: 40,
+    };
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "renounce",
+        Amount::zero(),
+        &partial,
+    )
+    .expect("Update succeeds with new renounce");
+    check_event(
+        &upd,
+        DAOEvent::Renounced {
+            proposal_id: 0,
+            voter: Address::Account(ACC_ADDR_OTHER),
+            total_votes: 160,
+        },
+    );
+
+    // Renouncing more than contributed renounces all, dropping the contribution.
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "renounce",
+        Amount::zero(),
+        &partial,
+    )
+    .expect("Update succeeds with new renounce");
+    check_event(
+        &upd,
+        DAOEvent::Renounced {
+            proposal_id: 0,
+            voter: Address::Account(ACC_ADDR_OWNER),
+            total_votes: 120,
+        },
+    );
+    let all = VoteInput {
+        proposal_id: 0,
+        votes: 1_000,
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "renounce",
+        Amount::zero(),
+        &all,
+    )
+    .expect("Update succeeds with new renounce");
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.all_proposals".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("Fetch all proposals");
+    let return_value: Vec<(u64, Proposal)> =
+        invoke.parse_return_value().expect("Proposals return value");
+    assert_eq!(return_value[0].1.votes, 60);
+    assert_eq!(
+        return_value[0].1.contributers,
+        vec![(Address::Account(ACC_ADDR_OTHER), 60)]
+    );
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.all_members".to_string()),
+                address: init.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("Fetch all members");
+    let return_value: Vec<(Address, u64)> =
+        invoke.parse_return_value().expect("Members return value");
+    assert_eq!(
+        return_value,
+        vec![
+            (Address::Account(ACC_ADDR_OWNER), 10_000_000),
+            (Address::Account(ACC_ADDR_OTHER), 9_999_940)
+        ]
+    );
+}
+
+#[test]
+fn test_zero_amounts() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::zero(),
+        &(),
+    )
+    .expect_err("Update fails with zero deposit");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::ZeroAmount);
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::zero(),
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect_err("Update fails with zero amount proposal");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::ZeroAmount);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: 0,
+    };
+    for entrypoint in ["vote", "renounce"] {
+        let update_err = update(
+            &mut chain,
+            ACC_ADDR_OWNER,
+            &init,
+            entrypoint,
+            Amount::zero(),
+            &v,
+        )
+        .expect_err("Update fails with zero votes");
+        let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+        assert_eq!(rv, DAOError::ZeroAmount);
+    }
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails with zero donation");
+    let rv: DAOError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, DAOError::ZeroAmount);
+}
+
+#[test]
+fn test_targeted_views() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(5),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    // Approving the first proposal, and voting on the second.
+    let v = VoteInput {
+        proposal_id: 0,
+        votes: 100_000,
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+    let v = VoteInput {
+        proposal_id: 1,
+        votes: 300,
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &v,
+    )
+    .expect("Update succeeds with new vote");
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Get proposal");
+    assert_eq!(proposal.votes, 300);
+    assert_eq!(
+        proposal.contributers,
+        vec![(Address::Account(ACC_ADDR_OWNER), 300)]
+    );
+
+    let rv = view::<_, Proposal>(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 2 },
+    )
+    .expect_err("Get proposal fails for unknown proposal");
+    assert_eq!(rv, DAOError::ProposalNotFound);
+
+    let member: MemberView = view(
+        &chain,
+        &init,
+        "get_member",
+        &Address::Account(ACC_ADDR_OWNER),
+    )
+    .expect("Get member");
+    assert_eq!(
+        member,
+        MemberView {
+            power: 15_000_000 - 100_300,
+            deposited: Amount::from_ccd(15),
+            active_votes: 300,
+            tier: None,
+        }
+    );
+
+    let rv = view::<_, MemberView>(
+        &chain,
+        &init,
+        "get_member",
+        &Address::Account(ACC_ADDR_OTHER),
+    )
+    .expect_err("Get member fails for non-member");
+    assert_eq!(rv, DAOError::NotAMember);
+
+    let votes: Vec<(u64, u64)> =
+        view(&chain, &init, "votes_of", &Address::Account(ACC_ADDR_OWNER)).expect("Votes of");
+    assert_eq!(votes, vec![(0, 100_000), (1, 300)]);
+
+    let votes: Vec<(u64, u64)> =
+        view(&chain, &init, "votes_of", &Address::Account(ACC_ADDR_OTHER)).expect("Votes of");
+    assert_eq!(votes, vec![]);
+}
+
+#[test]
+fn test_registry() {
+    let (mut chain, init) = setup_chain_and_contract();
+    let module_reference = chain
+        .get_contract(init.contract_address)
+        .expect("DAO exists")
+        .module_reference;
+
+    let registry = chain
+        .contract_init(
+            SIGNER,
+            ACC_ADDR_OTHER,
+            Energy::from(10_000),
+            InitContractPayload {
+                amount: Amount::zero(),
+                mod_ref: module_reference,
+                init_name: OwnedContractName::new_unchecked("init_DAORegistry".to_string()),
+                param: OwnedParameter::empty(),
+            },
+        )
+        .expect("Initialization should always succeed");
+
+    let register = |chain: &mut Chain, sender: AccountAddress, dao: ContractAddress| {
+        chain.contract_update(
+            SIGNER,
+            sender,
+            Address::Account(sender),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                address: registry.contract_address,
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAORegistry.register".to_string()),
+                message: OwnedParameter::from_serial(&RegisterInput { dao }).expect("Register DAO"),
+            },
+        )
+    };
+
+    // Only the creator can register the DAO.
+    let update_err = register(&mut chain, ACC_ADDR_OTHER, init.contract_address)
+        .expect_err("Update fails for non-creator");
+    let rv: RegistryError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, RegistryError::Unauthorized);
+
+    // Contracts other than a DAO can't be registered.
+    let update_err = register(&mut chain, ACC_ADDR_OTHER, registry.contract_address)
+        .expect_err("Update fails for non-DAO");
+    let rv: RegistryError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, RegistryError::NotADAO);
+
+    register(&mut chain, ACC_ADDR_OWNER, init.contract_address)
+        .expect("Update succeeds with registration");
+
+    let update_err = register(&mut chain, ACC_ADDR_OWNER, init.contract_address)
+        .expect_err("Update fails for registered DAO");
+    let rv: RegistryError = update_err.parse_return_value().expect("Deserialize Error");
+    assert_eq!(rv, RegistryError::AlreadyRegistered);
+
+    let expected_value = vec![(
+        init.contract_address,
+        DAOEntry {
+            name: DAO_NAME.to_string(),
+            creator: ACC_ADDR_OWNER,
+            registered_at: Timestamp::from_timestamp_millis(0),
+            config: dao_config(),
+        },
+    )];
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAORegistry.list".to_string()),
+                address: registry.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("List DAOs");
+    let return_value: Vec<(ContractAddress, DAOEntry)> =
+        invoke.parse_return_value().expect("DAOs return value");
+    assert_eq!(return_value, expected_value);
+
+    for (search, expected) in [
+        (
+            SearchInput {
+                name: Some("Kerala".to_string()),
+                creator: None,
+            },
+            expected_value.clone(),
+        ),
+        (
+            SearchInput {
+                name: Some("Kerala".to_string()),
+                creator: Some(ACC_ADDR_OTHER),
+            },
+            vec![],
+        ),
+    ] {
+        let invoke = chain
+            .contract_invoke(
+                ACC_ADDR_OWNER,
+                Address::Account(ACC_ADDR_OWNER),
+                Energy::from(10_000),
+                UpdateContractPayload {
+                    amount: Amount::zero(),
+                    receive_name: OwnedReceiveName::new_unchecked("DAORegistry.search".to_string()),
+                    address: registry.contract_address,
+                    message: OwnedParameter::from_serial(&search).expect("Search DAOs"),
+                },
+            )
+            .expect("Search DAOs");
+        let return_value: Vec<(ContractAddress, DAOEntry)> =
+            invoke.parse_return_value().expect("DAOs return value");
+        assert_eq!(return_value, expected);
+    }
+
+    // The entry is refreshed when the DAO updates its config.
+    let config = DAOConfig {
+        voting_period: Duration::from_millis(2 * VOTING_PERIOD_MILLIS),
+        ..dao_config()
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Extend the voting period".to_string(),
+            action: GovernanceAction::UpdateConfig(config.clone()),
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with vote");
+
+    let events: Vec<RegistryEvent> = update_vote
+        .events()
+        .filter(|(address, _)| *address == registry.contract_address)
+        .flat_map(|(_, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [RegistryEvent::Refreshed {
+            dao: init.contract_address
+        }]
+    );
+
+    let invoke = chain
+        .contract_invoke(
+            ACC_ADDR_OWNER,
+            Address::Account(ACC_ADDR_OWNER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAORegistry.list".to_string()),
+                address: registry.contract_address,
+                message: OwnedParameter::empty(),
+            },
+        )
+        .expect("List DAOs");
+    let return_value: Vec<(ContractAddress, DAOEntry)> =
+        invoke.parse_return_value().expect("DAOs return value");
+    assert_eq!(return_value[0].1.config, config);
+}
+
+#[test]
+fn test_supports() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    let implementor = ContractAddress::new(42, 0);
+    let cis3 = StandardIdentifierOwned::new_unchecked("CIS-3".to_string());
+    let query = SupportsQueryParams {
+        queries: vec![
+            CIS0_STANDARD_IDENTIFIER.to_owned(),
+            CIS2_STANDARD_IDENTIFIER.to_owned(),
+            cis3.clone(),
+        ],
+    };
+
+    let response: SupportsQueryResponse =
+        view(&chain, &init, "supports", &query).expect("Query supported standards");
+    assert!(matches!(
+        response.results[..],
+        [
+            SupportResult::Support,
+            SupportResult::Support,
+            SupportResult::NoSupport
+        ]
+    ));
+
+    // Only a guardian can set implementors.
+    let input = SetImplementorsInput {
+        id: cis3,
+        implementors: vec![implementor],
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "set_implementors",
+        Amount::zero(),
+        &input,
+    )
+    .expect_err("Update fails for non-guardian");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "set_implementors",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new implementors");
+
+    let response: SupportsQueryResponse =
+        view(&chain, &init, "supports", &query).expect("Query supported standards");
+    match &response.results[..] {
+        [SupportResult::Support, SupportResult::Support, SupportResult::SupportBy(addresses)] => {
+            assert_eq!(addresses, &[implementor])
+        }
+        results => panic!("Unexpected support results: {results:?}"),
+    }
+}
+
+#[test]
+fn test_impact_report() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    for _ in 0..2 {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &input,
+        )
+        .expect("Update succeeds with new proposal");
+    }
+    for proposal_id in 0..2 {
+        let v = VoteInput {
+            proposal_id,
+            votes: 100_000,
+        };
+        update(
+            &mut chain,
+            ACC_ADDR_OWNER,
+            &init,
+            "vote",
+            Amount::zero(),
+            &v,
+        )
+        .expect("Update succeeds with new vote");
+    }
+
+    let report = ReportInput {
+        proposal_id: 0,
+        report: MetadataUrl {
+            url: "https://example.com/reports/0.json".to_string(),
+            hash: Some([7; 32]),
+        },
+    };
+
+    // Reports can only be submitted for collected proposals.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "submit_report",
+        Amount::zero(),
+        &report,
+    )
+    .expect_err("Update fails for an uncollected proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotCollected);
+
+    for proposal_id in 0..2 {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "withdraw",
+            Amount::zero(),
+            &WithdrawInput { proposal_id },
+        )
+        .expect("Update succeeds with withdrawal");
+    }
+
+    // Only the proposer can report.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "submit_report",
+        Amount::zero(),
+        &report,
+    )
+    .expect_err("Update fails for non-proposer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    let update = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "submit_report",
+        Amount::zero(),
+        &report,
+    )
+    .expect("Update succeeds with new report");
+    check_event(
+        &update,
+        DAOEvent::Reported {
+            proposal_id: 0,
+            report: report.report.clone(),
+        },
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Reported);
+    assert_eq!(proposal.report, Some(report.report));
+
+    let record: TrackRecord = view(
+        &chain,
+        &init,
+        "track_record",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("Fetch track record");
+    assert_eq!(
+        record,
+        TrackRecord {
+            proposed: 2,
+            collected: 2,
+            reported: 1,
+            overdue: 0,
+        }
+    );
+
+    // Once the report period has passed, the unreported proposal flags the proposer.
+    chain
+        .tick_block_time(Duration::from_millis(REPORT_PERIOD_MILLIS))
+        .expect("Advance block time");
+
+    let record: TrackRecord = view(
+        &chain,
+        &init,
+        "track_record",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("Fetch track record");
+    assert_eq!(record.overdue, 1);
+
+    let update_err = chain
+        .contract_update(
+            SIGNER,
+            ACC_ADDR_OTHER,
+            Address::Account(ACC_ADDR_OTHER),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                address: init.contract_address,
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.create_proposal".to_string()),
+                message: OwnedParameter::from_serial(&input).expect("Create proposal"),
+            },
+        )
+        .expect_err("Update fails with overdue reports");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::ReportsOverdue);
+}
+
+#[test]
+fn test_dispute() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+
+    let flag = FlagInput {
+        proposal_id: 0,
+        reason: "No relief was delivered".to_string(),
+    };
+
+    // Only collected proposals can be flagged.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &flag,
+    )
+    .expect_err("Update fails for an uncollected proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotCollected);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 100_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with withdrawal");
+
+    // Only members can flag proposals.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &flag,
+    )
+    .expect_err("Update fails for non-member");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    let update_flag = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &flag,
+    )
+    .expect("Update succeeds with new dispute");
+    check_event(
+        &update_flag,
+        DAOEvent::Flagged {
+            dispute_id: 0,
+            proposal_id: 0,
+            flagger: Address::Account(ACC_ADDR_OWNER),
+        },
+    );
+
+    // A dispute without enough votes is rejected after the voting period, returning the votes.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_dispute",
+        Amount::zero(),
+        &DisputeVoteInput {
+            dispute_id: 0,
+            votes: 400_000,
+        },
+    )
+    .expect("Update succeeds with new dispute vote");
+    chain
+        .tick_block_time(Duration::from_millis(VOTING_PERIOD_MILLIS))
+        .expect("Advance block time");
+    let update_close = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "close_dispute",
+        Amount::zero(),
+        &DisputeIdInput { dispute_id: 0 },
+    )
+    .expect("Update succeeds with closing the dispute");
+    check_event(&update_close, DAOEvent::DisputeRejected { dispute_id: 0 });
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("Fetch power");
+    assert_eq!(power, 9_900_000);
+
+    // A rejected dispute can be raised again, and is upheld once it reaches the quorum.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &flag,
+    )
+    .expect("Update succeeds with new dispute");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_dispute",
+        Amount::zero(),
+        &DisputeVoteInput {
+            dispute_id: 1,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new dispute vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::DisputeVoted {
+                dispute_id: 1,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                total_votes: 1_000_000,
+            },
+            DAOEvent::DisputeUpheld {
+                dispute_id: 1,
+                blocked: Address::Account(ACC_ADDR_OTHER),
+            },
+        ]
+    );
+
+    let disputes: Vec<(u64, Dispute)> =
+        view(&chain, &init, "all_disputes", &()).expect("Fetch disputes");
+    assert_eq!(disputes[0].1.status, DisputeStatus::Rejected);
+    assert_eq!(disputes[1].1.status, DisputeStatus::Upheld);
+
+    let blocked: bool = view(
+        &chain,
+        &init,
+        "is_blocked",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("Fetch blocked");
+    assert!(blocked);
+
+    // Upheld disputes can't be raised again, and the proposer can no longer propose.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &flag,
+    )
+    .expect_err("Update fails for a disputed proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::AlreadyDisputed);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect_err("Update fails for a blocked proposer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Blocked);
+}
+
+#[test]
+fn test_membership_tiers() {
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        min_deposit: Amount::from_ccd(1),
+        tiers: vec![
+            Tier {
+                name: "Supporter".to_string(),
+                min_deposited: Amount::from_ccd(1),
+            },
+            Tier {
+                name: "Patron".to_string(),
+                min_deposited: Amount::from_ccd(100),
+            },
+        ],
+        proposal_gate: Some(ProposalGate {
+            amount: Amount::from_ccd(10),
+            tier: "Patron".to_string(),
+        }),
+        ..dao_config()
+    });
+
+    // The first deposit has to meet the minimum deposit.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_micro_ccd(1),
+        &(),
+    )
+    .expect_err("Update fails below the minimum deposit");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::BelowMinDeposit);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(1),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    // Members can top up any amount.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_micro_ccd(1),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let member: MemberView = view(
+        &chain,
+        &init,
+        "get_member",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("Fetch member");
+    assert_eq!(member.tier, Some("Supporter".to_string()));
+
+    // Proposals above the gated amount require the Patron tier.
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::from_ccd(50),
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect_err("Update fails without the required tier");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::TierRequired);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(99),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let member: MemberView = view(
+        &chain,
+        &init,
+        "get_member",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("Fetch member");
+    assert_eq!(member.tier, Some("Patron".to_string()));
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+}
+
+#[test]
+fn test_amend_proposal() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount { micro_ccd: 100_000 },
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &input,
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 40_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    let amend = AmendInput {
+        proposal_id: 0,
+        description: "Kerala Flood Relief Camps".to_string(),
+        amount: Amount { micro_ccd: 200_000 },
+    };
+
+    // Only the proposer can amend.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "amend_proposal",
+        Amount::zero(),
+        &amend,
+    )
+    .expect_err("Update fails for non-proposer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    // Increasing the amount returns the votes to the voters.
+    let update_amend = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "amend_proposal",
+        Amount::zero(),
+        &amend,
+    )
+    .expect("Update succeeds with amendment");
+    check_event(
+        &update_amend,
+        DAOEvent::Amended {
+            proposal_id: 0,
+            revision: 1,
+            description: amend.description.clone(),
+            amount: amend.amount,
+            votes_reset: true,
+        },
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.votes, 0);
+    assert!(proposal.contributers.is_empty());
+    assert_eq!(proposal.revision, 1);
+    assert_eq!(
+        proposal.previous_description_hash,
+        Some(
+            "fda3ffd7926aae80c5b4f49320d6b9e2dd4c2fdf47605b3bef24b4c6e8d388bd"
+                .parse::<concordium_std::HashSha2256>()
+                .expect("Parse hash")
+        )
+    );
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("Fetch power");
+    assert_eq!(power, 10_000_000);
+
+    // Decreasing the amount keeps the votes, and approves the proposal once they cover it.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 150_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "amend_proposal",
+        Amount::zero(),
+        &AmendInput {
+            amount: Amount { micro_ccd: 150_000 },
+            ..amend
+        },
+    )
+    .expect("Update succeeds with amendment");
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.votes, 150_000);
+    assert_eq!(proposal.revision, 2);
+    assert_eq!(proposal.status, Status::Approved);
+}
+
+#[test]
+fn test_quadratic_funding() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    let input = ProposalInput {
+        description: "Kerala Flood Relief".to_string(),
+        amount: Amount::from_ccd(1),
+    };
+    for _ in 0..2 {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &input,
+        )
+        .expect("Update succeeds with new proposal");
+    }
+
+    let update_round = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_round",
+        Amount::from_ccd(9),
+        &RoundInput {
+            duration: Duration::from_days(1),
+        },
+    )
+    .expect("Update succeeds with new round");
+    check_event(
+        &update_round,
+        DAOEvent::RoundCreated {
+            round_id: 0,
+            sponsor: Address::Account(ACC_ADDR_OWNER),
+            pool: Amount::from_ccd(9),
+        },
+    );
+
+    for (contributor, proposal_id, amount) in [
+        (ACC_ADDR_OWNER, 0, Amount::from_ccd(1)),
+        (ACC_ADDR_OTHER, 0, Amount::from_ccd(1)),
+        (ACC_ADDR_OWNER, 1, Amount::from_ccd(4)),
+        (ACC_ADDR_OTHER, 1, Amount::from_ccd(1)),
+    ] {
+        update(
+            &mut chain,
+            contributor,
+            &init,
+            "contribute",
+            amount,
+            &RoundProposalInput {
+                round_id: 0,
+                proposal_id,
+            },
+        )
+        .expect("Update succeeds with new contribution");
+    }
+
+    let round_id = RoundIdInput { round_id: 0 };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "close_round",
+        Amount::zero(),
+        &round_id,
+    )
+    .expect_err("Update fails for an open round");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::VotingOpen);
+
+    chain
+        .tick_block_time(Duration::from_days(1))
+        .expect("Advance block time");
+
+    // Proposal 0 scores (1000 + 1000)^2 - 2_000_000, proposal 1 scores (2000 + 1000)^2 - 5_000_000.
+    let update_close = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "close_round",
+        Amount::zero(),
+        &round_id,
+    )
+    .expect("Update succeeds with closing the round");
+    let events: Vec<DAOEvent> = update_close
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Matched {
+                round_id: 0,
+                proposal_id: 0,
+                contributed: Amount::from_ccd(2),
+                matched: Amount::from_ccd(3),
+            },
+            DAOEvent::Matched {
+                round_id: 0,
+                proposal_id: 1,
+                contributed: Amount::from_ccd(5),
+                matched: Amount::from_ccd(6),
+            },
+            DAOEvent::RoundClosed {
+                round_id: 0,
+                returned: Amount::zero(),
+            },
+        ]
+    );
+
+    let claim = RoundProposalInput {
+        round_id: 0,
+        proposal_id: 0,
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect_err("Update fails for non-proposer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    // The payout waits for the approval of the proposal.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect_err("Update fails for an active proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotApproved);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(1),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    let update_claim = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect("Update succeeds with claim");
+    check_event(
+        &update_claim,
+        DAOEvent::MatchClaimed {
+            round_id: 0,
+            proposal_id: 0,
+            proposer: Address::Account(ACC_ADDR_OTHER),
+            amount: Amount::from_ccd(5),
+        },
+    );
+    assert_eq!(
+        update_claim.account_transfers().collect::<Vec<_>>(),
+        [(init.contract_address, Amount::from_ccd(5), ACC_ADDR_OTHER)]
+    );
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect_err("Update fails for a claimed payout");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::AlreadyClaimed);
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("Fetch treasury");
+    assert_eq!(treasury.in_rounds, Amount::from_ccd(11));
+    assert_eq!(treasury.disbursed, Amount::from_ccd(5));
+    assert_eq!(treasury.free_balance, Amount::zero());
+
+    // A proposal vetoed after the round has closed doesn't receive its payout.
+    let release = RoundProposalInput {
+        round_id: 0,
+        proposal_id: 1,
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "release_match",
+        Amount::zero(),
+        &release,
+    )
+    .expect_err("Update fails for an active proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "veto",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Update succeeds with veto");
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &release,
+    )
+    .expect_err("Update fails for a vetoed proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotApproved);
+
+    let update_release = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "release_match",
+        Amount::zero(),
+        &release,
+    )
+    .expect("Update succeeds with release");
+    check_event(
+        &update_release,
+        DAOEvent::MatchReleased {
+            round_id: 0,
+            proposal_id: 1,
+            refunded: Amount::from_ccd(5),
+            returned: Amount::from_ccd(6),
+        },
+    );
+    assert_eq!(
+        update_release.account_transfers().collect::<Vec<_>>(),
+        [
+            (init.contract_address, Amount::from_ccd(4), ACC_ADDR_OWNER),
+            (init.contract_address, Amount::from_ccd(1), ACC_ADDR_OTHER),
+            (init.contract_address, Amount::from_ccd(6), ACC_ADDR_OWNER),
+        ]
+    );
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("Fetch treasury");
+    assert_eq!(treasury.in_rounds, Amount::zero());
+}
+
+#[test]
+fn test_round_refunds() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    for amount in [Amount::from_micro_ccd(100_000), Amount::from_ccd(100)] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &ProposalInput {
+                description: "Kerala Flood Relief".to_string(),
+                amount,
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_round",
+        Amount::from_ccd(9),
+        &RoundInput {
+            duration: Duration::from_days(1),
+        },
+    )
+    .expect("Update succeeds with new round");
+    for (contributor, proposal_id, amount) in [
+        (ACC_ADDR_OWNER, 0, Amount::from_ccd(1)),
+        (ACC_ADDR_OTHER, 0, Amount::from_ccd(1)),
+        (ACC_ADDR_OWNER, 1, Amount::from_ccd(2)),
+    ] {
+        update(
+            &mut chain,
+            contributor,
+            &init,
+            "contribute",
+            amount,
+            &RoundProposalInput {
+                round_id: 0,
+                proposal_id,
+            },
+        )
+        .expect("Update succeeds with new contribution");
+    }
+
+    // Contributions to cancelled proposals are refunded when the round is closed.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "cancel_proposal",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Update succeeds with cancellation");
+
+    // The proposer gets blocked through an upheld dispute on the collected proposal.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 100_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with withdrawal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &FlagInput {
+            proposal_id: 0,
+            reason: "No relief was delivered".to_string(),
+        },
+    )
+    .expect("Update succeeds with new dispute");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_dispute",
+        Amount::zero(),
+        &DisputeVoteInput {
+            dispute_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new dispute vote");
+
+    chain
+        .tick_block_time(Duration::from_days(1))
+        .expect("Advance block time");
+    let update_close = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "close_round",
+        Amount::zero(),
+        &RoundIdInput { round_id: 0 },
+    )
+    .expect("Update succeeds with closing the round");
+    let events: Vec<DAOEvent> = update_close
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Matched {
+                round_id: 0,
+                proposal_id: 0,
+                contributed: Amount::from_ccd(2),
+                matched: Amount::from_ccd(9),
+            },
+            DAOEvent::RoundClosed {
+                round_id: 0,
+                returned: Amount::zero(),
+            },
+        ]
+    );
+    assert_eq!(
+        update_close.account_transfers().collect::<Vec<_>>(),
+        [(init.contract_address, Amount::from_ccd(2), ACC_ADDR_OWNER)]
+    );
+
+    // The blocked proposer can't claim, but anyone can release the payout.
+    let claim = RoundProposalInput {
+        round_id: 0,
+        proposal_id: 0,
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect_err("Update fails for a blocked proposer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Blocked);
+
+    let update_release = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "release_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect("Update succeeds with release");
+    check_event(
+        &update_release,
+        DAOEvent::MatchReleased {
+            round_id: 0,
+            proposal_id: 0,
+            refunded: Amount::from_ccd(2),
+            returned: Amount::from_ccd(9),
+        },
+    );
+    assert_eq!(
+        update_release.account_transfers().collect::<Vec<_>>(),
+        [
+            (init.contract_address, Amount::from_ccd(1), ACC_ADDR_OWNER),
+            (init.contract_address, Amount::from_ccd(1), ACC_ADDR_OTHER),
+            (init.contract_address, Amount::from_ccd(9), ACC_ADDR_OWNER),
+        ]
+    );
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "release_match",
+        Amount::zero(),
+        &claim,
+    )
+    .expect_err("Update fails for a released payout");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::AlreadyClaimed);
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("Fetch treasury");
+    assert_eq!(treasury.in_rounds, Amount::zero());
+}
+
+#[test]
+fn test_multisig_payout() {
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        payout_limit: Some(Amount::from_ccd(1)),
+        ..dao_config()
+    });
+    let treasurer = AccountAddress([3; 32]);
+    chain.create_account(Account::new(treasurer, ACC_INITIAL_BALANCE));
+
+    let signers = Signers {
+        signers: vec![ACC_ADDR_OWNER, treasurer],
+        required: 2,
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "set_signers",
+        Amount::zero(),
+        &signers,
+    )
+    .expect_err("Update fails for non-guardian");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "set_signers",
+        Amount::zero(),
+        &Signers {
+            required: 3,
+            ..signers.clone()
+        },
+    )
+    .expect_err("Update fails with more required confirmations than signers");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InvalidConfig);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "set_signers",
+        Amount::zero(),
+        &signers,
+    )
+    .expect("Update succeeds with new signers");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_ccd(2),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: Amount::from_ccd(2).micro_ccd(),
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    let id = ProposalIdInput { proposal_id: 0 };
+    let withdraw = WithdrawInput { proposal_id: 0 };
+
+    // A single confirmation is not enough.
+    let update_confirm = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "confirm_payout",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with confirmation");
+    check_event(
+        &update_confirm,
+        DAOEvent::PayoutConfirmed {
+            proposal_id: 0,
+            signer: ACC_ADDR_OWNER,
+            confirmations: 1,
+        },
+    );
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &withdraw,
+    )
+    .expect_err("Update fails without enough confirmations");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::ConfirmationsMissing);
+
+    // Only signers can confirm.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "confirm_payout",
+        Amount::zero(),
+        &id,
+    )
+    .expect_err("Update fails for non-signer");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    // A revoked confirmation no longer counts.
+    update(
+        &mut chain,
+        treasurer,
+        &init,
+        "confirm_payout",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with confirmation");
+    let update_revoke = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "revoke_confirmation",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with revocation");
+    check_event(
+        &update_revoke,
+        DAOEvent::ConfirmationRevoked {
+            proposal_id: 0,
+            signer: ACC_ADDR_OWNER,
+            confirmations: 1,
+        },
+    );
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &withdraw,
+    )
+    .expect_err("Update fails without enough confirmations");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "confirm_payout",
+        Amount::zero(),
+        &id,
+    )
+    .expect("Update succeeds with confirmation");
+    let update_withdraw = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &withdraw,
+    )
+    .expect("Update succeeds with withdrawal");
+    assert_eq!(
+        update_withdraw.account_transfers().collect::<Vec<_>>(),
+        [(init.contract_address, Amount::from_ccd(2), ACC_ADDR_OTHER)]
+    );
+}
+
+#[test]
+fn test_governance_proposal() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    // Actions are validated on creation.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Gate large proposals".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                proposal_gate: Some(ProposalGate {
+                    amount: Amount::from_ccd(10),
+                    tier: "Patron".to_string(),
+                }),
+                ..dao_config()
+            }),
+        },
+    )
+    .expect_err("Update fails with an invalid config");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InvalidConfig);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Drop the governance threshold".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                governance_threshold: 0,
+                ..dao_config()
+            }),
+        },
+    )
+    .expect_err("Update fails with an invalid config");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InvalidConfig);
+
+    let config = DAOConfig {
+        voting_period: Duration::from_millis(2 * VOTING_PERIOD_MILLIS),
+        ..dao_config()
+    };
+    for (description, config) in [
+        ("Extend the voting period", config.clone()),
+        (
+            "Lower the dispute quorum",
+            DAOConfig {
+                dispute_quorum: 500_000,
+                ..dao_config()
+            },
+        ),
+    ] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_governance_proposal",
+            Amount::zero(),
+            &GovernanceInput {
+                description: description.to_string(),
+                action: GovernanceAction::UpdateConfig(config),
+            },
+        )
+        .expect("Update succeeds with new governance proposal");
+    }
+
+    // Governance proposals don't accept donations.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(1),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect_err("Update fails for a governance proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotFunding);
+
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Voted {
+                proposal_id: 0,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                accepted: 1_000_000,
+                returned: 0,
+                total_votes: 1_000_000,
+            },
+            DAOEvent::Executed { proposal_id: 0 },
+        ]
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Executed);
+
+    let info: DAOInfo = view(&chain, &init, "info", &()).expect("Fetch info");
+    assert_eq!(info.config, config);
+
+    // The votes are returned on execution.
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("Fetch power");
+    assert_eq!(power, 10_000_000);
+
+    // A config update created before the last update would revert it, so it expires instead.
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::ConfigOutdated { proposal_id: 1 }));
+    assert!(events.contains(&DAOEvent::Expired { proposal_id: 1 }));
+
+    let info: DAOInfo = view(&chain, &init, "info", &()).expect("Fetch info");
+    assert_eq!(info.config, config);
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("Fetch power");
+    assert_eq!(power, 10_000_000);
+}
+
+#[test]
+fn test_contract_call_proposal() {
+    let (mut chain, init) = setup_chain_and_contract();
+    let module_reference = chain
+        .get_contract(init.contract_address)
+        .expect("DAO exists")
+        .module_reference;
+
+    // Another DAO the treasury deposits into.
+    let target = chain
+        .contract_init(
+            SIGNER,
+            ACC_ADDR_OTHER,
+            Energy::from(10_000),
+            InitContractPayload {
+                amount: Amount::zero(),
+                mod_ref: module_reference,
+                init_name: OwnedContractName::new_unchecked("init_DAO".to_string()),
+                param: OwnedParameter::from_serial(&InitParams {
+                    name: "Wayanad Relief DAO".to_string(),
+                    config: dao_config(),
+                })
+                .expect("Init params"),
+            },
+        )
+        .expect("Initialization should always succeed");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Support the Wayanad Relief DAO".to_string(),
+            action: GovernanceAction::Invoke(ContractCall {
+                target: target.contract_address,
+                entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                    "insert".to_string(),
+                ),
+                parameter: concordium_std::OwnedParameter::empty(),
+                amount: Amount::from_ccd(20),
+            }),
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+
+    // The invocation fails as the free balance doesn't cover it, leaving the proposal approved.
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Voted {
+                proposal_id: 0,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                accepted: 1_000_000,
+                returned: 0,
+                total_votes: 1_000_000,
+            },
+            DAOEvent::InvokeFailed {
+                proposal_id: 0,
+                reason: CallFailure::InsufficientBalance,
+            },
+        ]
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+
+    // Once the treasury covers the amount, the execution can be retried.
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(15),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let update_execute = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "execute",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with execution");
+    // The target mints a donation receipt, so only the events of the DAO itself are checked.
+    let events: Vec<DAOEvent> = update_execute
+        .events()
+        .filter(|(addr, _events)| *addr == init.contract_address)
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Invoked {
+                proposal_id: 0,
+                target: target.contract_address,
+                amount: Amount::from_ccd(20),
+            },
+            DAOEvent::Executed { proposal_id: 0 },
+        ]
+    );
+    assert_eq!(
+        chain
+            .contract_balance(target.contract_address)
+            .expect("Target exists"),
+        Amount::from_ccd(20)
+    );
+
+    // The DAO itself became a member of the target, keyed on its contract address.
+    let member: MemberView = view(
+        &chain,
+        &target,
+        "get_member",
+        &Address::Contract(init.contract_address),
+    )
+    .expect("Get member");
+    assert_eq!(member.power, Amount::from_ccd(20).micro_ccd());
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "execute",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect_err("Update fails for an executed proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotApproved);
+}
+
+#[test]
+fn test_reserve() {
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        reserve_ratio_bps: 2_000,
+        reserve_floor: Amount::from_ccd(1),
+        ..dao_config()
+    });
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("Fetch treasury");
+    assert_eq!(treasury.reserve, Amount::from_ccd(2));
+    assert_eq!(treasury.free_balance, Amount::from_ccd(8));
+
+    for amount in [Amount::from_ccd(8), Amount::from_ccd(9)] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &ProposalInput {
+                description: "Kerala Flood Relief".to_string(),
+                amount,
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+
+    // Proposals can't be approved out of the reserve.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: Amount::from_ccd(9).micro_ccd(),
+        },
+    )
+    .expect_err("Update fails for a proposal exceeding the free balance");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InsufficientBalance);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: Amount::from_ccd(8).micro_ccd(),
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    // Raising the reserve ratio blocks payouts breaching the reserve.
+    let governance_votes = VoteInput {
+        proposal_id: 2,
+        votes: 1_000_000,
+    };
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Raise the reserve".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                reserve_ratio_bps: 5_000,
+                reserve_floor: Amount::from_ccd(1),
+                ..dao_config()
+            }),
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &governance_votes,
+    )
+    .expect("Update succeeds with new vote");
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect_err("Update fails for a payout breaching the reserve");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::ReserveBreached);
+
+    // The reserve can only be released through emergency proposals.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Emergency relief".to_string(),
+            action: GovernanceAction::ReleaseReserve(ReserveRelease {
+                recipient: ACC_ADDR_OTHER,
+                amount: Amount::from_ccd(3),
+            }),
+        },
+    )
+    .expect("Update succeeds with new emergency proposal");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 3,
+            ..governance_votes
+        },
+    )
+    .expect("Update succeeds with new vote");
+    assert_eq!(
+        update_vote.account_transfers().collect::<Vec<_>>(),
+        [(init.contract_address, Amount::from_ccd(3), ACC_ADDR_OTHER)]
+    );
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("Fetch treasury");
+    assert_eq!(treasury.reserve, Amount::from_ccd(2));
+    assert_eq!(treasury.disbursed, Amount::from_ccd(3));
+
+    // Releases exceeding what is left of the reserve fail, leaving the proposal approved.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "More emergency relief".to_string(),
+            action: GovernanceAction::ReleaseReserve(ReserveRelease {
+                recipient: ACC_ADDR_OTHER,
+                amount: Amount::from_ccd(3),
+            }),
+        },
+    )
+    .expect("Update succeeds with new emergency proposal");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 4,
+            ..governance_votes
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Voted {
+                proposal_id: 4,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                accepted: 1_000_000,
+                returned: 0,
+                total_votes: 1_000_000,
+            },
+            DAOEvent::ReleaseFailed {
+                proposal_id: 4,
+                reason: ReleaseFailure::ExceedsReserve,
+            },
+        ]
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 4 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+}
+
+#[test]
+fn test_donation_receipts() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    for amount in [Amount::from_ccd(10), Amount::from_ccd(5)] {
+        update(&mut chain, ACC_ADDR_OTHER, &init, "insert", amount, &()).expect("Insert succeeds");
+    }
+    let insert = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(1),
+        &(),
+    )
+    .expect("Insert succeeds");
+
+    // Every deposit mints a new receipt to the donor.
+    let events: Vec<Cis2Event<ContractTokenId, ContractTokenAmount>> = insert
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    let receipt = Receipt {
+        owner: Address::Account(ACC_ADDR_OWNER),
+        amount: Amount::from_ccd(1),
+        timestamp: chain.block_time(),
+    };
+    assert_eq!(
+        events,
+        [
+            Cis2Event::Mint(MintEvent {
+                token_id: TokenIdU32(2),
+                amount: TokenAmountU8(1),
+                owner: Address::Account(ACC_ADDR_OWNER),
+            }),
+            Cis2Event::TokenMetadata(TokenMetadataEvent {
+                token_id: TokenIdU32(2),
+                metadata_url: receipt.metadata_url(TokenIdU32(2), init.contract_address),
+            }),
+        ]
+    );
+
+    let receipts: Vec<(ContractTokenId, Receipt)> = view(
+        &chain,
+        &init,
+        "receipts_of",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("View receipts");
+    assert_eq!(
+        receipts
+            .iter()
+            .map(|(id, receipt)| (*id, receipt.amount))
+            .collect::<Vec<_>>(),
+        [
+            (TokenIdU32(0), Amount::from_ccd(10)),
+            (TokenIdU32(1), Amount::from_ccd(5))
+        ]
+    );
+
+    let balances: BalanceOfQueryResponse<ContractTokenAmount> = view(
+        &chain,
+        &init,
+        "balanceOf",
+        &BalanceOfQueryParams {
+            queries: vec![
+                BalanceOfQuery {
+                    token_id: TokenIdU32(0),
+                    address: Address::Account(ACC_ADDR_OTHER),
+                },
+                BalanceOfQuery {
+                    token_id: TokenIdU32(2),
+                    address: Address::Account(ACC_ADDR_OTHER),
+                },
+            ],
+        },
+    )
+    .expect("Query balances");
+    assert_eq!(balances.0, [TokenAmountU8(1), TokenAmountU8(0)]);
+
+    let metadata: TokenMetadataQueryResponse = view(
+        &chain,
+        &init,
+        "tokenMetadata",
+        &TokenMetadataQueryParams {
+            queries: vec![TokenIdU32(2)],
+        },
+    )
+    .expect("Query metadata");
+    assert_eq!(
+        metadata.0,
+        [receipt.metadata_url(TokenIdU32(2), init.contract_address)]
+    );
+
+    // The metadata is a percent-encoded JSON data URL.
+    let json = metadata.0[0]
+        .url
+        .strip_prefix("data:application/json,")
+        .map(percent_decode)
+        .expect("JSON data URL");
+    assert_eq!(
+        json,
+        format!(
+            "{{\"name\":\"Donation receipt 2\",\"attributes\":[\
+             {{\"type\":\"string\",\"name\":\"amount\",\"value\":\"1000000\"}},\
+             {{\"type\":\"string\",\"name\":\"timestamp\",\"value\":\"{}\"}},\
+             {{\"type\":\"string\",\"name\":\"dao\",\"value\":\"<{},{}>\"}}]}}",
+            chain.block_time().timestamp_millis(),
+            init.contract_address.index,
+            init.contract_address.subindex
+        )
+    );
+    assert!(!metadata.0[0].url.contains(['"', '<', ' ']));
+
+    // Receipts are non-transferable by default.
+    let transfer =
+        TransferParams::<ContractTokenId, ContractTokenAmount>(vec![concordium_cis2::Transfer {
+            token_id: TokenIdU32(0),
+            amount: TokenAmountU8(1),
+            from: Address::Account(ACC_ADDR_OTHER),
+            to: Receiver::Account(ACC_ADDR_OWNER),
+            data: AdditionalData::empty(),
+        }]);
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "transfer",
+        Amount::zero(),
+        &transfer,
+    )
+    .expect_err("Transfer of a receipt fails");
+    let rv: Cis2Error<DAOError> = update_err
+        .parse_return_value()
+        .expect("Deserialize ContractError");
+    assert_eq!(rv, Cis2Error::Custom(DAOError::NonTransferable));
+}
+
+#[test]
+fn test_transferable_receipts() {
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        receipts_transferable: true,
+        ..dao_config()
+    });
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Insert succeeds");
+
+    let transfer =
+        TransferParams::<ContractTokenId, ContractTokenAmount>(vec![concordium_cis2::Transfer {
+            token_id: TokenIdU32(0),
+            amount: TokenAmountU8(1),
+            from: Address::Account(ACC_ADDR_OTHER),
+            to: Receiver::Account(ACC_ADDR_OWNER),
+            data: AdditionalData::empty(),
+        }]);
+
+    // Only the owner or its operators can transfer the receipt.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "transfer",
+        Amount::zero(),
+        &transfer,
+    )
+    .expect_err("Transfer by a non-owner fails");
+    let rv: Cis2Error<DAOError> = update_err
+        .parse_return_value()
+        .expect("Deserialize ContractError");
+    assert_eq!(rv, Cis2Error::Unauthorized);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "updateOperator",
+        Amount::zero(),
+        &UpdateOperatorParams(vec![UpdateOperator {
+            update: OperatorUpdate::Add,
+            operator: Address::Account(ACC_ADDR_OWNER),
+        }]),
+    )
+    .expect("Adding an operator succeeds");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "transfer",
+        Amount::zero(),
+        &transfer,
+    )
+    .expect("Transfer by an operator succeeds");
+
+    let receipts: Vec<(ContractTokenId, Receipt)> = view(
+        &chain,
+        &init,
+        "receipts_of",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("View receipts");
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].1.amount, Amount::from_ccd(10));
+}
+
+#[test]
+fn test_operations_fee() {
+    let operations = AccountAddress([4; 32]);
+    let config = DAOConfig {
+        fee_bps: 250,
+        operations_account: Some(operations),
+        min_deposit: Amount::from_ccd(50),
+        ..dao_config()
+    };
+    let (mut chain, init) = setup_chain_and_contract_with(config);
+    chain.create_account(Account::new(operations, Amount::zero()));
+
+    // Nothing to collect before any deposits.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "collect_fees",
+        Amount::zero(),
+        &(),
+    )
+    .expect_err("Collecting fails without fees");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::ZeroAmount);
+
+    // The minimum deposit applies to the net amount.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(51),
+        &(),
+    )
+    .expect_err("Inserting fails below the minimum deposit after the fee");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::BelowMinDeposit);
+
+    let update_insert = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(100),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    let events: Vec<DAOEvent> = update_insert
+        .events()
+        .flat_map(|(_addr, events)| events.iter().filter_map(|e| e.parse().ok()))
+        .collect();
+    assert_eq!(
+        events,
+        [DAOEvent::FeeCharged {
+            donor: Address::Account(ACC_ADDR_OTHER),
+            amount: Amount::from_ccd(100),
+            fee: Amount::from_micro_ccd(2_500_000),
+        }]
+    );
+
+    // Only the net amount becomes voting power.
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 97_500_000);
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("View treasury");
+    assert_eq!(treasury.total_deposited, Amount::from_micro_ccd(97_500_000));
+    assert_eq!(treasury.fees, Amount::from_micro_ccd(2_500_000));
+    assert_eq!(treasury.free_balance, Amount::from_micro_ccd(97_500_000));
+
+    let update_collect = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "collect_fees",
+        Amount::zero(),
+        &(),
+    )
+    .expect("Update succeeds with collecting fees");
+    check_event(
+        &update_collect,
+        DAOEvent::FeesCollected {
+            account: operations,
+            amount: Amount::from_micro_ccd(2_500_000),
+        },
+    );
+    assert_eq!(
+        chain.account_balance_available(operations),
+        Some(Amount::from_micro_ccd(2_500_000))
+    );
+
+    let treasury: TreasuryView = view(&chain, &init, "treasury", &()).expect("View treasury");
+    assert_eq!(treasury.fees, Amount::zero());
+    assert_eq!(treasury.balance, Amount::from_micro_ccd(97_500_000));
+
+    // A fee cannot be charged without an operations account.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Drop the operations account".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                fee_bps: 250,
+                ..dao_config()
+            }),
+        },
+    )
+    .expect_err("Update fails with an invalid config");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InvalidConfig);
+
+    // Nor can the fee take the whole deposit.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Charge everything".to_string(),
+            action: GovernanceAction::UpdateConfig(DAOConfig {
+                fee_bps: 10_000,
+                operations_account: Some(operations),
+                ..dao_config()
+            }),
+        },
+    )
+    .expect_err("Update fails with an invalid config");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::InvalidConfig);
+}
+
+#[test]
+fn test_euro_proposal() {
+    // The test chain exchanges 50000 microCCD per euro.
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    let update_create = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_euro_proposal",
+        Amount::zero(),
+        &EuroProposalInput {
+            description: "Kerala Flood Relief".to_string(),
+            amount: EuroAmount {
+                euro_cents: 10_000,
+                max_slippage_bps: 500,
+            },
+        },
+    )
+    .expect("Update succeeds with new proposal");
+    check_event(
+        &update_create,
+        DAOEvent::ProposalCreated {
+            proposal_id: 0,
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_ccd(5),
+        },
+    );
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Wayanad Landslide Relief".to_string(),
+            amount: Amount::from_ccd(1),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+
+    let value: ProposalValue = view(
+        &chain,
+        &init,
+        "get_proposal_value",
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("View proposal value");
+    assert_eq!(
+        value,
+        ProposalValue {
+            amount: Amount::from_ccd(1),
+            euro_cents: 2_000,
+            current: Amount::from_ccd(1),
+        }
+    );
+
+    // Proposals denominated in EUR can't be amended with a CCD amount.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "amend_proposal",
+        Amount::zero(),
+        &AmendInput {
+            proposal_id: 0,
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_ccd(4),
+        },
+    )
+    .expect_err("Amending fails for a EUR proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::EuroDenominated);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 5_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+    assert_eq!(proposal.amount, Amount::from_ccd(5));
+
+    // A 20% move of the exchange rate exceeds the slippage bound of 5%.
+    let mut chain = reprice(chain, 60_000);
+    let value: ProposalValue = view(
+        &chain,
+        &init,
+        "get_proposal_value",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("View proposal value");
+    assert_eq!(
+        value,
+        ProposalValue {
+            amount: Amount::from_ccd(5),
+            euro_cents: 10_000,
+            current: Amount::from_ccd(6),
+        }
+    );
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect_err("Withdrawing fails beyond the slippage bound");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::SlippageExceeded);
+
+    // A 4% move is within the bound; the payout follows the exchange rate.
+    let mut chain = reprice(chain, 52_000);
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with withdrawal");
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Collected);
+    assert_eq!(proposal.amount, Amount::from_micro_ccd(5_200_000));
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_micro_ccd(4_800_000))
+    );
+}
+
+#[test]
+fn test_euro_donations() {
+    // The test chain exchanges 50000 microCCD per euro.
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(1),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    for description in ["Kerala Flood Relief", "Wayanad Landslide Relief"] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_euro_proposal",
+            Amount::zero(),
+            &EuroProposalInput {
+                description: description.to_string(),
+                amount: EuroAmount {
+                    euro_cents: 10_000,
+                    max_slippage_bps: 500,
+                },
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+
+    // Donations are capped at the amount at the current exchange rate.
+    let mut chain = reprice(chain, 60_000);
+    let upd = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(10),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with new donation");
+    let events: Vec<DAOEvent> = upd
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::Donated {
+        proposal_id: 0,
+        donor: Address::Account(ACC_ADDR_OWNER),
+        amount: Amount::from_ccd(6),
+    }));
+    assert!(upd
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OWNER && amount == Amount::from_ccd(4)));
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+    assert_eq!(proposal.amount, Amount::from_ccd(6));
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "donate_to_proposal",
+        Amount::from_ccd(5),
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Update succeeds with new donation");
+
+    // Donations beyond a lowered amount are refunded on approval.
+    let mut chain = reprice(chain, 40_000);
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 1,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::DonationRefunded {
+        proposal_id: 1,
+        donor: Address::Account(ACC_ADDR_OWNER),
+        amount: Amount::from_ccd(1),
+    }));
+    assert!(update_vote
+        .account_transfers()
+        .any(|(_, amount, to)| to == ACC_ADDR_OWNER && amount == Amount::from_ccd(1)));
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+    assert_eq!(proposal.amount, Amount::from_ccd(4));
+    assert_eq!(
+        proposal.donations,
+        vec![(Address::Account(ACC_ADDR_OWNER), Amount::from_ccd(4))]
+    );
+}
+
+#[test]
+fn test_vote_batch() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    for description in [
+        "Kerala Flood Relief",
+        "Wayanad Landslide Relief",
+        "Chennai Cyclone Relief",
+    ] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &ProposalInput {
+                description: description.to_string(),
+                amount: Amount::from_ccd(100),
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_batch",
+        Amount::zero(),
+        &vec![
+            VoteInput {
+                proposal_id: 0,
+                votes: 1_000_000,
+            },
+            VoteInput {
+                proposal_id: 1,
+                votes: 2_000_000,
+            },
+        ],
+    )
+    .expect("Update succeeds with a batch of votes");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Voted {
+                proposal_id: 0,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                accepted: 1_000_000,
+                returned: 0,
+                total_votes: 1_000_000,
+            },
+            DAOEvent::Voted {
+                proposal_id: 1,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                accepted: 2_000_000,
+                returned: 0,
+                total_votes: 2_000_000,
+            },
+        ]
+    );
+
+    // A single failing vote fails the whole batch.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_batch",
+        Amount::zero(),
+        &vec![
+            VoteInput {
+                proposal_id: 2,
+                votes: 1_000_000,
+            },
+            VoteInput {
+                proposal_id: 3,
+                votes: 1_000_000,
+            },
+        ],
+    )
+    .expect_err("Update fails for an unknown proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::ProposalNotFound);
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 2 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.votes, 0);
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 7_000_000);
+
+    let update_renounce = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "renounce_batch",
+        Amount::zero(),
+        &vec![
+            VoteInput {
+                proposal_id: 0,
+                votes: 1_000_000,
+            },
+            VoteInput {
+                proposal_id: 1,
+                votes: 500_000,
+            },
+        ],
+    )
+    .expect("Update succeeds with a batch of renouncements");
+    let events: Vec<DAOEvent> = update_renounce
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            DAOEvent::Renounced {
+                proposal_id: 0,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                total_votes: 0,
+            },
+            DAOEvent::Renounced {
+                proposal_id: 1,
+                voter: Address::Account(ACC_ADDR_OWNER),
+                total_votes: 1_500_000,
+            },
+        ]
+    );
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 8_500_000);
+}
+
+#[test]
+fn test_commit_reveal() {
+    const REVEAL_PERIOD_MILLIS: u64 = 24 * 60 * 60 * 1000;
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        reveal_period: Some(Duration::from_millis(REVEAL_PERIOD_MILLIS)),
+        ..dao_config()
+    });
+    let salt = [7; 32];
+
+    for sender in [ACC_ADDR_OWNER, ACC_ADDR_OTHER] {
+        update(
+            &mut chain,
+            sender,
+            &init,
+            "insert",
+            Amount::from_ccd(10),
+            &(),
+        )
+        .expect("Update succeeds with new insert");
+    }
+    for description in ["Kerala Flood Relief", "Wayanad Landslide Relief"] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &ProposalInput {
+                description: description.to_string(),
+                amount: Amount::from_ccd(5),
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+
+    // Votes on commit-reveal proposals have to be committed.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 5_000_000,
+        },
+    )
+    .expect_err("Voting fails on a commit-reveal proposal");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::CommitRevealRequired);
+
+    let commitment: concordium_std::HashSha2256 = view(
+        &chain,
+        &init,
+        "vote_commitment",
+        &CommitmentInput {
+            voter: Address::Account(ACC_ADDR_OWNER),
+            proposal_id: 0,
+            votes: 5_000_000,
+            salt,
+        },
+    )
+    .expect("Compute commitment");
+    let update_commit = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "commit_vote",
+        Amount::zero(),
+        &CommitInput {
+            proposal_id: 0,
+            commitment,
+        },
+    )
+    .expect("Update succeeds with new commitment");
+    check_event(
+        &update_commit,
+        DAOEvent::VoteCommitted {
+            proposal_id: 0,
+            voter: Address::Account(ACC_ADDR_OWNER),
+        },
+    );
+
+    // The other member commits, but never reveals.
+    let commitment: concordium_std::HashSha2256 = view(
+        &chain,
+        &init,
+        "vote_commitment",
+        &CommitmentInput {
+            voter: Address::Account(ACC_ADDR_OTHER),
+            proposal_id: 1,
+            votes: 5_000_000,
+            salt,
+        },
+    )
+    .expect("Compute commitment");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "commit_vote",
+        Amount::zero(),
+        &CommitInput {
+            proposal_id: 1,
+            commitment,
+        },
+    )
+    .expect("Update succeeds with new commitment");
+
+    let reveal = RevealInput {
+        proposal_id: 0,
+        votes: 5_000_000,
+        salt,
+    };
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "reveal_vote",
+        Amount::zero(),
+        &reveal,
+    )
+    .expect_err("Revealing fails during the commit phase");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::VotingOpen);
+
+    chain
+        .tick_block_time(Duration::from_millis(VOTING_PERIOD_MILLIS))
+        .expect("Tick block time");
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "reveal_vote",
+        Amount::zero(),
+        &RevealInput {
+            votes: 4_000_000,
+            ..reveal
+        },
+    )
+    .expect_err("Revealing fails for other votes than committed");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::CommitmentMismatch);
+
+    let update_reveal = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "reveal_vote",
+        Amount::zero(),
+        &RevealInput {
+            proposal_id: 0,
+            votes: 5_000_000,
+            salt,
+        },
+    )
+    .expect("Update succeeds with revealed votes");
+    check_event(
+        &update_reveal,
+        DAOEvent::Voted {
+            proposal_id: 0,
+            voter: Address::Account(ACC_ADDR_OWNER),
+            accepted: 5_000_000,
+            returned: 0,
+            total_votes: 5_000_000,
+        },
+    );
+
+    // The tally is only final after the reveal phase.
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Active);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "tally",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect_err("Tallying fails during the reveal phase");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::VotingOpen);
+
+    chain
+        .tick_block_time(Duration::from_millis(REVEAL_PERIOD_MILLIS))
+        .expect("Tick block time");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "tally",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with tally");
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+
+    // Unrevealed commitments count for nothing, and forfeit nothing.
+    let update_tally = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "tally",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Update succeeds with tally");
+    check_event(&update_tally, DAOEvent::Expired { proposal_id: 1 });
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 10_000_000);
+}
+
+#[test]
+fn test_commit_reveal_unfunded() {
+    const REVEAL_PERIOD_MILLIS: u64 = 24 * 60 * 60 * 1000;
+    let (mut chain, init) = setup_chain_and_contract_with(DAOConfig {
+        reveal_period: Some(Duration::from_millis(REVEAL_PERIOD_MILLIS)),
+        reserve_ratio_bps: 5_000,
+        ..dao_config()
+    });
+    let salt = [7; 32];
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_ccd(8),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+
+    let commitment: concordium_std::HashSha2256 = view(
+        &chain,
+        &init,
+        "vote_commitment",
+        &CommitmentInput {
+            voter: Address::Account(ACC_ADDR_OWNER),
+            proposal_id: 0,
+            votes: 8_000_000,
+            salt,
+        },
+    )
+    .expect("Compute commitment");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "commit_vote",
+        Amount::zero(),
+        &CommitInput {
+            proposal_id: 0,
+            commitment,
+        },
+    )
+    .expect("Update succeeds with new commitment");
+
+    chain
+        .tick_block_time(Duration::from_millis(VOTING_PERIOD_MILLIS))
+        .expect("Tick block time");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "reveal_vote",
+        Amount::zero(),
+        &RevealInput {
+            proposal_id: 0,
+            votes: 8_000_000,
+            salt,
+        },
+    )
+    .expect("Update succeeds with revealed votes");
+    chain
+        .tick_block_time(Duration::from_millis(REVEAL_PERIOD_MILLIS))
+        .expect("Tick block time");
+
+    // Half of the treasury is reserved, so the threshold is reached but the payout isn't covered.
+    let update_tally = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "tally",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with tally");
+    check_event(&update_tally, DAOEvent::Expired { proposal_id: 0 });
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Expired);
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 10_000_000);
+}
+
+#[test]
+fn test_oversubscribed_vote() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    for sender in [ACC_ADDR_OWNER, ACC_ADDR_OTHER] {
+        update(
+            &mut chain,
+            sender,
+            &init,
+            "insert",
+            Amount::from_ccd(10),
+            &(),
+        )
+        .expect("Update succeeds with new insert");
+    }
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_ccd(3),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    // Only the votes needed to reach the threshold are accepted.
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 5_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    check_event(
+        &update_vote,
+        DAOEvent::Voted {
+            proposal_id: 0,
+            voter: Address::Account(ACC_ADDR_OTHER),
+            accepted: 2_000_000,
+            returned: 3_000_000,
+            total_votes: 3_000_000,
+        },
+    );
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+    assert_eq!(
+        proposal.contributers,
+        [
+            (Address::Account(ACC_ADDR_OWNER), 1_000_000),
+            (Address::Account(ACC_ADDR_OTHER), 2_000_000)
+        ]
+    );
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OTHER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 8_000_000);
+
+    // Lowering the amount below the votes returns the surplus on approval.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Wayanad Landslide Relief".to_string(),
+            amount: Amount::from_ccd(5),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 4_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let update_amend = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "amend_proposal",
+        Amount::zero(),
+        &AmendInput {
+            proposal_id: 1,
+            description: "Wayanad Landslide Relief".to_string(),
+            amount: Amount::from_ccd(3),
+        },
+    )
+    .expect("Update succeeds with amendment");
+    let events: Vec<DAOEvent> = update_amend
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    println!("{events:?}");
+    assert!(events.contains(&DAOEvent::VotesReturned {
+        proposal_id: 1,
+        voter: Address::Account(ACC_ADDR_OWNER),
+        votes: 1_000_000,
+    }));
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 1 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Approved);
+    assert_eq!(proposal.votes, 3_000_000);
+    assert_eq!(
+        proposal.contributers,
+        [(Address::Account(ACC_ADDR_OWNER), 3_000_000)]
+    );
+
+    let power: u64 = view(
+        &chain,
+        &init,
+        "get_power",
+        &AddressInput {
+            address: Address::Account(ACC_ADDR_OWNER),
+        },
+    )
+    .expect("View power");
+    assert_eq!(power, 6_000_000);
+}
+
+#[test]
+fn test_dissolution() {
+    let (mut chain, init) = setup_chain_and_contract();
+    let stranger = AccountAddress([5; 32]);
+    chain.create_account(Account::new(stranger, ACC_INITIAL_BALANCE));
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(30),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+
+    for (description, amount) in [
+        ("Kerala Flood Relief", Amount::from_ccd(5)),
+        ("Wayanad Landslide Relief", Amount::from_ccd(2)),
+    ] {
+        update(
+            &mut chain,
+            ACC_ADDR_OTHER,
+            &init,
+            "create_proposal",
+            Amount::zero(),
+            &ProposalInput {
+                description: description.to_string(),
+                amount,
+            },
+        )
+        .expect("Update succeeds with new proposal");
+    }
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 2_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Dissolve the DAO".to_string(),
+            action: GovernanceAction::Dissolve,
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 2,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::Cancelled { proposal_id: 0 }));
+    // The approved proposal stays committed and is not part of the distribution.
+    assert!(events.contains(&DAOEvent::Dissolved {
+        proposal_id: 2,
+        pool: Amount::from_ccd(38),
+    }));
+
+    let proposal: Proposal = view(
+        &chain,
+        &init,
+        "get_proposal",
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Fetch proposal");
+    assert_eq!(proposal.status, Status::Cancelled);
+
+    // New proposals, votes and deposits are frozen.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Chennai Cyclone Relief".to_string(),
+            amount: Amount::from_ccd(1),
+        },
+    )
+    .expect_err("Creating a proposal fails after dissolution");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Dissolved);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect_err("Voting fails after dissolution");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Dissolved);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(1),
+        &(),
+    )
+    .expect_err("Depositing fails after dissolution");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Dissolved);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "flag_proposal",
+        Amount::zero(),
+        &FlagInput {
+            proposal_id: 1,
+            reason: "No relief was delivered".to_string(),
+        },
+    )
+    .expect_err("Flagging fails after dissolution");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Dissolved);
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote_dispute",
+        Amount::zero(),
+        &DisputeVoteInput {
+            dispute_id: 0,
+            votes: 1_000_000,
+        },
+    )
+    .expect_err("Voting on disputes fails after dissolution");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Dissolved);
+
+    // Members claim pro rata to their deposits, once.
+    let update_claim = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "claim_share",
+        Amount::zero(),
+        &(),
+    )
+    .expect("Update succeeds with claim");
+    check_event(
+        &update_claim,
+        DAOEvent::ShareClaimed {
+            member: Address::Account(ACC_ADDR_OWNER),
+            amount: Amount::from_micro_ccd(28_500_000),
+        },
+    );
+
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "claim_share",
+        Amount::zero(),
+        &(),
+    )
+    .expect_err("Claiming fails for the second time");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::AlreadyClaimed);
+
+    let update_err = update(
+        &mut chain,
+        stranger,
+        &init,
+        "claim_share",
+        Amount::zero(),
+        &(),
+    )
+    .expect_err("Claiming fails for non-members");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NotAMember);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "claim_share",
+        Amount::zero(),
+        &(),
+    )
+    .expect("Update succeeds with claim");
+
+    let dissolution: Option<Dissolution> =
+        view(&chain, &init, "dissolution", &()).expect("View dissolution");
+    assert_eq!(
+        dissolution,
+        Some(Dissolution {
+            pool: Amount::from_ccd(38),
+            deposits: Amount::from_ccd(40),
+            claimed: Amount::from_ccd(38),
+            claimed_deposits: Amount::from_ccd(40),
+            claimants: vec![
+                Address::Account(ACC_ADDR_OWNER),
+                Address::Account(ACC_ADDR_OTHER)
+            ],
+        })
+    );
+
+    // Approved proposals can still be collected.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 1 },
+    )
+    .expect("Update succeeds with withdrawal");
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::zero())
+    );
+}
+
+#[test]
+fn test_dissolution_remainder() {
+    let (mut chain, init) = setup_chain_and_contract();
+
+    for (sender, amount) in [
+        (ACC_ADDR_OWNER, Amount::from_ccd(2)),
+        (ACC_ADDR_OTHER, Amount::from_ccd(1)),
+    ] {
+        update(&mut chain, sender, &init, "insert", amount, &())
+            .expect("Update succeeds with new insert");
+    }
+
+    // Paying out a single microCCD leaves a pool that doesn't split evenly.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Kerala Flood Relief".to_string(),
+            amount: Amount::from_micro_ccd(1),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 0,
+            votes: 1,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &init,
+        "withdraw",
+        Amount::zero(),
+        &WithdrawInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with withdrawal");
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Dissolve the DAO".to_string(),
+            action: GovernanceAction::Dissolve,
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+
+    // The first claim rounds down and the last claimant receives the remainder.
+    for (member, amount) in [
+        (ACC_ADDR_OWNER, Amount::from_micro_ccd(1_999_999)),
+        (ACC_ADDR_OTHER, Amount::from_micro_ccd(1_000_000)),
+    ] {
+        let update_claim = update(
+            &mut chain,
+            member,
+            &init,
+            "claim_share",
+            Amount::zero(),
+            &(),
+        )
+        .expect("Update succeeds with claim");
+        check_event(
+            &update_claim,
+            DAOEvent::ShareClaimed {
+                member: Address::Account(member),
+                amount,
+            },
+        );
+    }
+
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::zero())
+    );
+}
+
+#[test]
+fn test_contract_refund() {
+    let (mut chain, init) = setup_chain_and_contract();
+    // Another DAO the treasury donates to.
+    let target = init_target_dao(&mut chain, &init);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(30),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Wayanad Landslide Relief".to_string(),
+            amount: Amount::from_ccd(100),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+
+    let update_donate = invoke_through_governance(
+        &mut chain,
+        &init,
+        0,
+        ContractCall {
+            target: target.contract_address,
+            entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                "donate_to_proposal".to_string(),
+            ),
+            parameter: concordium_std::OwnedParameter::from_serial(&ProposalIdInput {
+                proposal_id: 0,
+            })
+            .expect("Serialize parameter"),
+            amount: Amount::from_ccd(10),
+        },
+    );
+    let events: Vec<DAOEvent> = update_donate
+        .events()
+        .filter(|(addr, _events)| *addr == init.contract_address)
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::Executed { proposal_id: 0 }));
+
+    // The DAO has no payout entrypoint, which doesn't block the veto of the target.
+    let update_veto = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "veto",
+        Amount::zero(),
+        &ProposalIdInput { proposal_id: 0 },
+    )
+    .expect("Update succeeds with veto");
+    let events: Vec<DAOEvent> = update_veto
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::RefundDeferred {
+        recipient: init.contract_address,
+        amount: Amount::from_ccd(10),
+    }));
+
+    let treasury: TreasuryView = view(&chain, &target, "treasury", &()).expect("View treasury");
+    assert_eq!(treasury.owed, Amount::from_ccd(10));
+    assert_eq!(treasury.free_balance, Amount::zero());
+
+    // Only contracts have refunds to claim.
+    let update_err = update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &target,
+        "claim_refund",
+        Amount::zero(),
+        &ClaimRefundInput {
+            entrypoint: concordium_std::OwnedEntrypointName::new_unchecked("insert".to_string()),
+        },
+    )
+    .expect_err("Claiming fails for accounts");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::Unauthorized);
+
+    // The DAO claims the refund through an entrypoint of its choice.
+    let update_claim = invoke_through_governance(
+        &mut chain,
+        &init,
+        1,
+        ContractCall {
+            target: target.contract_address,
+            entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                "claim_refund".to_string(),
+            ),
+            parameter: concordium_std::OwnedParameter::from_serial(&ClaimRefundInput {
+                entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                    "insert".to_string(),
+                ),
+            })
+            .expect("Serialize parameter"),
+            amount: Amount::zero(),
+        },
+    );
+    let events: Vec<DAOEvent> = update_claim
+        .events()
+        .filter(|(addr, _events)| *addr == target.contract_address)
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [DAOEvent::RefundClaimed {
+            recipient: init.contract_address,
+            amount: Amount::from_ccd(10),
+        }]
+    );
+
+    let treasury: TreasuryView = view(&chain, &target, "treasury", &()).expect("View treasury");
+    assert_eq!(treasury.owed, Amount::zero());
+    assert_eq!(
+        chain.contract_balance(init.contract_address),
+        Some(Amount::from_ccd(30))
+    );
+
+    // Nothing is owed once the refund is claimed.
+    let update_err = chain
+        .contract_update(
+            SIGNER,
+            ACC_ADDR_OWNER,
+            Address::Contract(init.contract_address),
+            Energy::from(10_000),
+            UpdateContractPayload {
+                address: target.contract_address,
+                amount: Amount::zero(),
+                receive_name: OwnedReceiveName::new_unchecked("DAO.claim_refund".to_string()),
+                message: OwnedParameter::from_serial(&ClaimRefundInput {
+                    entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                        "insert".to_string(),
+                    ),
+                })
+                .expect("Serialize parameter"),
+            },
+        )
+        .expect_err("Claiming fails without a refund owed");
+    let rv: DAOError = update_err
+        .parse_return_value()
+        .expect("Deserialize DAOError");
+    assert_eq!(rv, DAOError::NothingOwed);
+}
+
+#[test]
+fn test_dissolution_owed_refund() {
+    let (mut chain, init) = setup_chain_and_contract();
+    let target = init_target_dao(&mut chain, &init);
+
+    update(
+        &mut chain,
+        ACC_ADDR_OWNER,
+        &init,
+        "insert",
+        Amount::from_ccd(30),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "insert",
+        Amount::from_ccd(10),
+        &(),
+    )
+    .expect("Update succeeds with new insert");
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "create_proposal",
+        Amount::zero(),
+        &ProposalInput {
+            description: "Wayanad Landslide Relief".to_string(),
+            amount: Amount::from_ccd(100),
+        },
+    )
+    .expect("Update succeeds with new proposal");
+    invoke_through_governance(
+        &mut chain,
+        &init,
+        0,
+        ContractCall {
+            target: target.contract_address,
+            entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                "donate_to_proposal".to_string(),
+            ),
+            parameter: concordium_std::OwnedParameter::from_serial(&ProposalIdInput {
+                proposal_id: 0,
+            })
+            .expect("Serialize parameter"),
+            amount: Amount::from_ccd(10),
+        },
+    );
+
+    // Dissolving cancels the proposal, deferring the refund of the donating DAO.
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "create_governance_proposal",
+        Amount::zero(),
+        &GovernanceInput {
+            description: "Dissolve the DAO".to_string(),
+            action: GovernanceAction::Dissolve,
+        },
+    )
+    .expect("Update succeeds with new governance proposal");
+    let update_vote = update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "vote",
+        Amount::zero(),
+        &VoteInput {
+            proposal_id: 1,
+            votes: 1_000_000,
+        },
+    )
+    .expect("Update succeeds with new vote");
+    let events: Vec<DAOEvent> = update_vote
+        .events()
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert!(events.contains(&DAOEvent::RefundDeferred {
+        recipient: init.contract_address,
+        amount: Amount::from_ccd(10),
+    }));
+    // The owed refund is not part of the distribution.
+    assert!(events.contains(&DAOEvent::Dissolved {
+        proposal_id: 1,
+        pool: Amount::from_ccd(10),
+    }));
+
+    update(
+        &mut chain,
+        ACC_ADDR_OTHER,
+        &target,
+        "claim_share",
+        Amount::zero(),
+        &(),
+    )
+    .expect("Update succeeds with claim");
+
+    // The refund stays claimable after every member has claimed.
+    let update_claim = invoke_through_governance(
+        &mut chain,
+        &init,
+        1,
+        ContractCall {
+            target: target.contract_address,
+            entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                "claim_refund".to_string(),
+            ),
+            parameter: concordium_std::OwnedParameter::from_serial(&ClaimRefundInput {
+                entrypoint: concordium_std::OwnedEntrypointName::new_unchecked(
+                    "insert".to_string(),
+                ),
+            })
+            .expect("Serialize parameter"),
+            amount: Amount::zero(),
+        },
+    );
+    let events: Vec<DAOEvent> = update_claim
+        .events()
+        .filter(|(addr, _events)| *addr == target.contract_address)
+        .flat_map(|(_addr, events)| events.iter().map(|e| e.parse().expect("Deserialize event")))
+        .collect();
+    assert_eq!(
+        events,
+        [DAOEvent::RefundClaimed {
+            recipient: init.contract_address,
+            amount: Amount::from_ccd(10),
+        }]
+    );
+    assert_eq!(
+        chain.contract_balance(target.contract_address),
+        Some(Amount::zero())
+    );
+}